@@ -0,0 +1,142 @@
+use std::{collections::BTreeMap, fs, io, path::{Path, PathBuf}};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::days::{Answer, Part};
+
+/// Name of the answers file kept next to the inputs it belongs to, e.g. `input/answers.toml`.
+pub const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+/// Outcome of comparing an answer with the one stored in the answers file.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Unknown
+}
+
+impl Verdict {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail { .. } => "FAIL",
+            Verdict::Unknown => "UNKNOWN"
+        }
+    }
+}
+
+#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part1: Option<Answer>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    part2: Option<Answer>
+}
+
+impl DayAnswers {
+    fn part_mut(&mut self, part: Part) -> &mut Option<Answer> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2
+        }
+    }
+}
+
+/// Known-correct answers, stored as TOML with one `[year.dayN]` table holding `part1` and `part2`.
+/// Each answer is an integer, a string or an array of grid rows.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct Answers {
+    years: BTreeMap<String, BTreeMap<String, DayAnswers>>
+}
+
+impl Answers {
+    /// Loads the answers file at `path`. A missing file is treated as having no answers yet.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, AnswersError> {
+        let file_path = path.as_ref().to_path_buf();
+
+        match fs::read_to_string(&file_path) {
+            Ok(contents) => Self::from_toml(&contents).map_err(|e| {
+                AnswersError::ParseError {
+                    file_path,
+                    source: e
+                }
+            }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(error) => Err(AnswersError::ReadError {
+                file_path,
+                source: error
+            })
+        }
+    }
+
+    pub fn from_toml(contents: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(contents).map(|years| { Answers { years } })
+    }
+
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string(&self.years)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), AnswersError> {
+        let file_path = path.as_ref().to_path_buf();
+        let contents = self.to_toml().map_err(|e| { AnswersError::SerializeError { source: e } })?;
+
+        fs::write(&file_path, contents).map_err(|e| {
+            AnswersError::WriteError {
+                file_path,
+                source: e
+            }
+        })
+    }
+
+    pub fn expected(&self, year: u16, day: u8, part: Part) -> Option<&Answer> {
+        let answers = self.years.get(&year.to_string())?.get(&Self::day_key(day))?;
+        let answer = match part {
+            Part::One => &answers.part1,
+            Part::Two => &answers.part2
+        };
+        return answer.as_ref()
+    }
+
+    pub fn verify(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Verdict {
+        match self.expected(year, day, part) {
+            Some(expected) if answer.matches(expected) => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.clone() },
+            None => Verdict::Unknown
+        }
+    }
+
+    /// Stores `answer` as the correct one, replacing whatever was recorded before.
+    pub fn record(&mut self, year: u16, day: u8, part: Part, answer: Answer) {
+        let answers = self.years.entry(year.to_string()).or_default().entry(Self::day_key(day)).or_default();
+        *answers.part_mut(part) = Some(answer);
+    }
+
+    fn day_key(day: u8) -> String {
+        format!("day{}", day)
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum AnswersError {
+    #[error("Failed to read answers from {}", file_path.display())]
+    ReadError {
+        file_path: PathBuf,
+        source: io::Error
+    },
+    #[error("Failed to parse answers in {}", file_path.display())]
+    ParseError {
+        file_path: PathBuf,
+        source: toml::de::Error
+    },
+    #[error("Failed to serialize answers")]
+    SerializeError {
+        source: toml::ser::Error
+    },
+    #[error("Failed to write answers to {}", file_path.display())]
+    WriteError {
+        file_path: PathBuf,
+        source: io::Error
+    }
+}
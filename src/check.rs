@@ -0,0 +1,190 @@
+use std::fmt;
+
+use crate::{days::{DayError, InputSource, Part, registry::{Registry, Solver}}, runner::error_chain};
+
+/// Something about an input worth knowing before solving it. Only errors make `check` fail.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum InputIssue {
+    Empty,
+    /// The day's parser rejected the input.
+    ParseFailed { message: String },
+    CrlfLineEndings { lines: usize },
+    MissingTrailingNewline,
+    LeadingBlankLines { count: usize },
+    TrailingBlankLines { count: usize },
+    /// More than one blank line in a row, starting at `line`.
+    RepeatedBlankLines { line: usize },
+    /// `count` lines end in spaces or tabs, the first of them being `line`.
+    TrailingWhitespace {
+        line: usize,
+        count: usize
+    },
+    /// The input is one of the day's examples rather than a real puzzle input.
+    LooksLikeExample
+}
+
+impl InputIssue {
+    pub fn is_error(&self) -> bool {
+        matches!(self, InputIssue::Empty | InputIssue::ParseFailed { .. })
+    }
+}
+
+impl fmt::Display for InputIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputIssue::Empty => write!(f, "the input is empty"),
+            InputIssue::ParseFailed { message } => write!(f, "{}", message),
+            InputIssue::CrlfLineEndings { lines } => write!(f, "{} in CRLF", count_of(*lines, "line ends", "lines end")),
+            InputIssue::MissingTrailingNewline => write!(f, "the last line has no line ending"),
+            InputIssue::LeadingBlankLines { count } => write!(f, "{} before the first line", count_of(*count, "blank line", "blank lines")),
+            InputIssue::TrailingBlankLines { count } => write!(f, "{} after the last line", count_of(*count, "blank line", "blank lines")),
+            InputIssue::RepeatedBlankLines { line } => write!(f, "several blank lines in a row from line {}", line),
+            InputIssue::TrailingWhitespace { line, count } => write!(f, "{} in whitespace, starting with line {}", count_of(*count, "line ends", "lines end"), line),
+            InputIssue::LooksLikeExample => write!(f, "this is the puzzle's example, not a real input")
+        }
+    }
+}
+
+/// What `check` found out about one input file.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InputCheck {
+    pub location: String,
+    /// The parts of the day that read this input.
+    pub parts: Vec<Part>,
+    pub lines: usize,
+    /// Runs of non-blank lines, e.g. one per Elf for Day 1.
+    pub groups: usize,
+    /// The day's own account of what it parsed, when parsing succeeded.
+    pub description: Option<String>,
+    pub issues: Vec<InputIssue>
+}
+
+impl InputCheck {
+    pub fn is_ok(&self) -> bool {
+        !self.issues.iter().any(InputIssue::is_error)
+    }
+}
+
+impl fmt::Display for InputCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts = self.parts.iter().map(Part::to_string).collect::<Vec<String>>().join(" and ");
+        writeln!(f, "{} (part {})", self.location, parts)?;
+        write!(f, "  {} in {}", count_of(self.lines, "line", "lines"), count_of(self.groups, "group", "groups"))?;
+        if let Some(description) = &self.description {
+            write!(f, "\n  {}", description)?;
+        }
+        for issue in self.issues.iter() {
+            let level = if issue.is_error() { "error" } else { "warning" };
+            write!(f, "\n  {}: {}", level, issue.to_string().replace('\n', "\n    "))?;
+        }
+
+        return Ok(())
+    }
+}
+
+/// Checks the input of each part of a day, running the day's parser but not solving anything.
+/// Parts that read the same input share one check.
+pub fn check_day(year: u16, day: u8, source: &InputSource) -> Result<Vec<InputCheck>, DayError> {
+    let solvers = [Part::One, Part::Two].into_iter()
+        .filter_map(|part| { Registry::get(year, day, part) })
+        .collect::<Vec<Solver>>();
+    if solvers.is_empty() {
+        return Err(DayError::InvalidDay { year, day })
+    }
+
+    let mut checks: Vec<InputCheck> = Vec::new();
+    for (index, solver) in solvers.iter().enumerate() {
+        if let Some(check) = checks.last_mut().filter(|_| { solver.shares_input(&solvers[index - 1], source) }) {
+            check.parts.push(solver.key.part);
+            continue
+        }
+
+        let raw = solver.read(source)?;
+        checks.push(check_input(solver, location(solver, source), &raw));
+    }
+
+    return Ok(checks)
+}
+
+/// Checks one input, parsed with `solver`'s parser.
+pub fn check_input(solver: &Solver, location: String, raw: &str) -> InputCheck {
+    let lines = raw.lines().collect::<Vec<&str>>();
+    let mut issues = Vec::new();
+
+    if raw.is_empty() {
+        issues.push(InputIssue::Empty);
+    }
+
+    let (description, parse_failure) = match solver.parse(raw) {
+        Ok(parsed) => (solver.describe(&parsed), None),
+        Err(error) => (None, Some(InputIssue::ParseFailed { message: error_chain(&error) }))
+    };
+    issues.extend(parse_failure);
+
+    let crlf_lines = raw.matches("\r\n").count();
+    if crlf_lines > 0 {
+        issues.push(InputIssue::CrlfLineEndings { lines: crlf_lines });
+    }
+    if !raw.is_empty() && !raw.ends_with('\n') {
+        issues.push(InputIssue::MissingTrailingNewline);
+    }
+
+    let leading = lines.iter().take_while(|line| { is_blank(line) }).count();
+    let trailing = lines.iter().rev().take_while(|line| { is_blank(line) }).count();
+    if leading < lines.len() {
+        if leading > 0 {
+            issues.push(InputIssue::LeadingBlankLines { count: leading });
+        }
+        if trailing > 0 {
+            issues.push(InputIssue::TrailingBlankLines { count: trailing });
+        }
+
+        let inner = &lines[leading..lines.len() - trailing];
+        let repeated = inner.windows(2).position(|pair| { pair.iter().all(|line| { is_blank(line) }) });
+        if let Some(index) = repeated {
+            issues.push(InputIssue::RepeatedBlankLines { line: leading + index + 1 });
+        }
+    }
+
+    // Padding is part of a positional input, e.g. Day 5's crate drawing, so it is only suspicious elsewhere.
+    let padded = lines.iter().enumerate()
+        .filter(|_| { !solver.solution.positional_input })
+        .filter(|(_, line)| { !is_blank(line) && line.ends_with([' ', '\t']) })
+        .map(|(index, _)| { index + 1 })
+        .collect::<Vec<usize>>();
+    if let Some(&line) = padded.first() {
+        issues.push(InputIssue::TrailingWhitespace { line, count: padded.len() });
+    }
+
+    let normalized = raw.replace("\r\n", "\n");
+    if !raw.is_empty() && solver.solution.examples.iter().any(|example| { example.input.trim_end() == normalized.trim_end() }) {
+        issues.push(InputIssue::LooksLikeExample);
+    }
+
+    let groups = lines.split(|line| { is_blank(line) }).filter(|group| { !group.is_empty() }).count();
+
+    return InputCheck {
+        location,
+        parts: vec![solver.key.part],
+        lines: lines.len(),
+        groups,
+        description,
+        issues
+    }
+}
+
+fn count_of(count: usize, singular: &str, plural: &str) -> String {
+    format!("{} {}", count, if count == 1 { singular } else { plural })
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+fn location(solver: &Solver, source: &InputSource) -> String {
+    match source {
+        InputSource::Directory(input_dir) => solver.input_path(input_dir).display().to_string(),
+        InputSource::File(path) => path.display().to_string(),
+        InputSource::Text(_) => String::from("<stdin>")
+    }
+}
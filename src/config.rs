@@ -0,0 +1,50 @@
+use std::{fs, io, path::{Path, PathBuf}};
+
+use serde::Deserialize;
+use thiserror::Error;
+
+/// Local settings that should not be committed, e.g. the Advent of Code session token.
+pub const CONFIG_FILE_NAME: &str = "aoc.toml";
+
+/// Contents of the config file. Every setting is optional and command line flags win over it.
+#[derive(Clone, Default, PartialEq, Eq, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged-in adventofcode.com browser session.
+    pub session: Option<String>
+}
+
+impl Config {
+    /// Loads the config file at `path`. A missing file is treated as an empty config.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        let file_path = path.as_ref().to_path_buf();
+
+        match fs::read_to_string(&file_path) {
+            Ok(contents) => toml::from_str(&contents).map_err(|e| {
+                ConfigError::ParseError {
+                    file_path,
+                    source: e
+                }
+            }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(error) => Err(ConfigError::ReadError {
+                file_path,
+                source: error
+            })
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("Failed to read config from {}", file_path.display())]
+    ReadError {
+        file_path: PathBuf,
+        source: io::Error
+    },
+    #[error("Failed to parse config in {}", file_path.display())]
+    ParseError {
+        file_path: PathBuf,
+        source: toml::de::Error
+    }
+}
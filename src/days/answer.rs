@@ -0,0 +1,100 @@
+use std::{convert::Infallible, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// A puzzle answer. Serialises as a bare number, a string or a list of grid rows.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    /// Several lines to be read as a whole, one string per row: a rendered picture, e.g. letters drawn in `#` and `.`,
+    /// or one value per line of the input.
+    Grid(Vec<String>)
+}
+
+impl Answer {
+    pub fn grid<I: IntoIterator<Item = S>, S: Into<String>>(rows: I) -> Self {
+        Answer::Grid(rows.into_iter().map(Into::into).collect())
+    }
+
+    /// Whether this answer is the same as `expected`. Text is compared with what the other
+    /// answer displays as, so an integer still matches an expected answer typed as `"24000"`.
+    pub fn matches(&self, expected: &Answer) -> bool {
+        match (self, expected) {
+            (Answer::Text(_), _) | (_, Answer::Text(_)) => self.to_string() == expected.to_string(),
+            _ => self == expected
+        }
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Grid(rows) if rows.len() > 1)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n"))
+        }
+    }
+}
+
+macro_rules! answer_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Answer::Integer(i64::from(value))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_integer!(i8, i16, i32, i64, u8, u16, u32);
+
+/// Integers too large for `i64` are kept exactly as text rather than truncated.
+macro_rules! answer_from_wide_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    match i64::try_from(value) {
+                        Ok(value) => Answer::Integer(value),
+                        Err(_) => Answer::Text(value.to_string())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+answer_from_wide_integer!(u64, usize, i128, u128);
+
+/// Reads an answer typed by hand: whole numbers become integers, anything else is text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+        return Ok(match i64::from_str(input) {
+            Ok(value) => Answer::Integer(value),
+            Err(_) => Answer::Text(input.to_string())
+        })
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
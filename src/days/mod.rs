@@ -1,36 +1,165 @@
-pub mod day1;
-pub mod day1a;
-pub mod day2;
-pub mod day2a;
+pub mod answer;
+pub mod registry;
+pub mod y2022;
+
+use std::{fmt, io, path::{Path, PathBuf}};
 
 use thiserror::Error;
 
-use self::{day1::Day1, day1a::Day1a, day2::Day2, day2a::Day2a};
+use crate::parser::InputError;
+
+use self::registry::Registry;
+
+pub use self::answer::Answer;
+
+pub type DayResult = Result<Answer, DayError>;
+
+/// The Advent of Code event the CLI works on unless `--year` picks another.
+pub const DEFAULT_YEAR: u16 = 2022;
+
+/// Directory holding one `{year}` directory of `{day}.txt` input files per event when no other is configured.
+pub const DEFAULT_INPUT_DIR: &str = "./input";
+
+/// Which half of a day's puzzle is being solved. `Two` is the "advanced" part.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Part {
+    One,
+    Two
+}
+
+impl Part {
+    pub const fn from_advanced(advanced: bool) -> Self {
+        if advanced { Part::Two } else { Part::One }
+    }
+
+    /// The part's number as the puzzle text writes it, `1` or `2`.
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2
+        }
+    }
+
+    pub fn is_advanced(&self) -> bool {
+        *self == Part::Two
+    }
+
+    /// Suffix used for advanced parts in day names, e.g. `1a`.
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            Part::One => "",
+            Part::Two => "a"
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2")
+        }
+    }
+}
+
+/// Where a solver takes its puzzle input from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's own file inside this directory, see `DaySolution::build_input_path`.
+    Directory(PathBuf),
+    /// One specific file, whichever day is being solved.
+    File(PathBuf),
+    /// Input that has already been read, e.g. from stdin.
+    Text(String)
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Directory(PathBuf::from(DEFAULT_INPUT_DIR))
+    }
+}
+
+/// An official puzzle example along with the answers the puzzle text gives for it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Example {
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>
+}
 
-pub type DayResult = Result<String, DayError>;
+impl Example {
+    pub fn answer(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2
+        }
+    }
+}
 
+/// One day of the puzzle. The input is parsed once and both parts are pure functions over it.
 pub trait DaySolution {
+    /// The event the puzzle is from. Solutions live in that event's `y{year}` module.
+    const YEAR: u16;
     const DAY: u8;
-    const ADVANCED: bool;
 
-    fn solve() -> DayResult;
+    /// Examples from the puzzle text, checked by `cargo test` through the same parse and solve path as the real input.
+    const EXAMPLES: &'static [Example] = &[];
+
+    /// Whether characters are read by their column, so lines padded with spaces are valid and `check` does not warn about them.
+    const POSITIONAL_INPUT: bool = false;
+
+    /// The puzzle input once it has been parsed out of the raw text.
+    type Input: Send + Sync + 'static;
+
+    /// Day-specific error returned while parsing or solving. It must be `Send` so `--jobs` can hand results between threads.
+    type Error: std::error::Error + Send + Sync + 'static;
 
-    fn build_input_path() -> String {
-        let advanced_suffix = if Self::ADVANCED { "a" } else { "" };
-        let input_path = format!("./input/{}{}.txt", Self::DAY, advanced_suffix);
-        return input_path
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+
+    fn part1(input: &Self::Input) -> Result<Answer, Self::Error>;
+
+    fn part2(input: &Self::Input) -> Result<Answer, Self::Error>;
+
+    /// A short account of what was parsed for `check` to show, e.g. "3 Elves carrying 7 items of food".
+    fn describe(_input: &Self::Input) -> Option<String> {
+        None
     }
+
+    fn solve(part: Part, input: &Self::Input) -> Result<Answer, Self::Error> {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input)
+        }
+    }
+
+    fn build_input_path(input_dir: &Path, part: Part) -> PathBuf {
+        default_input_path(input_dir, Self::YEAR, Self::DAY, part)
+    }
+}
+
+/// Where a part reads its input unless its solution overrides `DaySolution::build_input_path`: the part's own
+/// override file when there is one, otherwise the input both parts share.
+pub fn default_input_path(input_dir: &Path, year: u16, day: u8, part: Part) -> PathBuf {
+    let override_path = part_input_path(input_dir, year, day, part);
+    return if override_path.exists() { override_path } else { day_input_path(input_dir, year, day) }
+}
+
+/// The input both parts of a day read, e.g. `input/2022/1.txt`. This is the file `fetch` downloads.
+pub fn day_input_path(input_dir: &Path, year: u16, day: u8) -> PathBuf {
+    input_dir.join(year.to_string()).join(format!("{}.txt", day))
+}
+
+/// A hand-made input for just one part, e.g. `input/2022/1.part2.txt`, for the rare puzzle whose parts need different input.
+pub fn part_input_path(input_dir: &Path, year: u16, day: u8, part: Part) -> PathBuf {
+    input_dir.join(year.to_string()).join(format!("{}.part{}.txt", day, part.number()))
 }
 
 
 
-pub fn solve_day(day: u8, advanced: bool) -> DayResult {
-    match (day, advanced) {
-        (1, false) => Day1::solve(),
-        (1, true) => Day1a::solve(),
-        (2, false) => Day2::solve(),
-        (2, true) => Day2a::solve(),
-        _ => Err(DayError::InvalidDay { day })
+pub fn solve_day(year: u16, day: u8, advanced: bool, source: &InputSource) -> DayResult {
+    match Registry::get(year, day, Part::from_advanced(advanced)) {
+        Some(solver) => solver.solve(source),
+        None => Err(DayError::InvalidDay { year, day })
     }
 }
 
@@ -38,12 +167,28 @@ pub fn solve_day(day: u8, advanced: bool) -> DayResult {
 
 #[derive(Error, Debug)]
 pub enum DayError {
-    #[error("Invalid Day: {day}")]
-    InvalidDay { day: u8 },
-    #[error("Error returned from internal solver for Day {day}{}", if *advanced {"a"} else {""})]
+    #[error("Invalid Day: {day} of {year}")]
+    InvalidDay { year: u16, day: u8 },
+    #[error("Failed to load input for Day {day}{}", part.suffix())]
+    InputError {
+        day: u8,
+        part: Part,
+        source: InputError
+    },
+    #[error("Error returned from internal solver for Day {day}{}", part.suffix())]
     InternalDayError {
         day: u8,
-        advanced: bool,
-        source: Box<dyn std::error::Error>
+        part: Part,
+        source: Box<dyn std::error::Error + Send + Sync>
+    }
+}
+
+impl DayError {
+    /// Whether the input file could not be opened because it does not exist.
+    pub fn is_missing_input(&self) -> bool {
+        match self {
+            DayError::InputError { source: InputError::OpenError { source, .. }, .. } => source.kind() == io::ErrorKind::NotFound,
+            _ => false
+        }
     }
-}
\ No newline at end of file
+}
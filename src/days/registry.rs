@@ -0,0 +1,203 @@
+use std::{any::Any, borrow::Cow, error::Error, fmt, path::{Path, PathBuf}, time::Instant};
+
+use log::debug;
+
+use crate::{parser::read_input, runner::{PhaseTimings, format_duration}};
+
+use super::{Answer, DaySolution, DayError, DayResult, Example, InputSource, Part};
+
+/// Key that uniquely identifies a solver in the registry.
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct SolverKey {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part
+}
+
+/// Shows the key the way days are named in messages, e.g. `2022 Day 1a`.
+impl fmt::Display for SolverKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} Day {}{}", self.year, self.day, self.part.suffix())
+    }
+}
+
+/// A day's parsed input with its type erased, so one parse can be handed to both parts.
+pub struct ParsedInput(Box<dyn Any + Send + Sync>);
+
+type ParseFn = fn(&str) -> Result<ParsedInput, Box<dyn Error + Send + Sync>>;
+type SolveFn = fn(&ParsedInput, Part) -> Result<Answer, Box<dyn Error + Send + Sync>>;
+type DescribeFn = fn(&ParsedInput) -> Option<String>;
+
+/// A registered `DaySolution` along with the metadata needed to look it up and describe it.
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    pub examples: &'static [Example],
+    pub positional_input: bool,
+    input_path: fn(&Path, Part) -> PathBuf,
+    parse: ParseFn,
+    solve: SolveFn,
+    describe: DescribeFn
+}
+
+impl Solution {
+    pub const fn new<T: DaySolution>(name: &'static str) -> Self {
+        Solution {
+            year: T::YEAR,
+            day: T::DAY,
+            name,
+            examples: T::EXAMPLES,
+            positional_input: T::POSITIONAL_INPUT,
+            input_path: T::build_input_path,
+            parse: parse_erased::<T>,
+            solve: solve_erased::<T>,
+            describe: describe_erased::<T>
+        }
+    }
+}
+
+fn parse_erased<T: DaySolution>(raw: &str) -> Result<ParsedInput, Box<dyn Error + Send + Sync>> {
+    match T::parse(raw) {
+        Ok(input) => Ok(ParsedInput(Box::new(input))),
+        Err(error) => Err(Box::new(error))
+    }
+}
+
+fn solve_erased<T: DaySolution>(input: &ParsedInput, part: Part) -> Result<Answer, Box<dyn Error + Send + Sync>> {
+    let input = input.0.downcast_ref::<T::Input>().expect("ParsedInput handed to a different solution than the one that parsed it");
+    match T::solve(part, input) {
+        Ok(answer) => Ok(answer),
+        Err(error) => Err(Box::new(error))
+    }
+}
+
+fn describe_erased<T: DaySolution>(input: &ParsedInput) -> Option<String> {
+    let input = input.0.downcast_ref::<T::Input>().expect("ParsedInput handed to a different solution than the one that parsed it");
+    T::describe(input)
+}
+
+inventory::collect!(Solution);
+
+/// Registers a `DaySolution` so both of its parts can be found through `Registry`. Call it once, next to the solution's impl.
+macro_rules! register_solution {
+    ($solution:ident) => {
+        inventory::submit! {
+            $crate::days::registry::Solution::new::<$solution>(stringify!($solution))
+        }
+    };
+}
+
+pub(crate) use register_solution;
+
+/// One part of a registered `Solution`.
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub key: SolverKey,
+    pub solution: &'static Solution
+}
+
+impl Solver {
+    pub fn name(&self) -> &'static str {
+        self.solution.name
+    }
+
+    /// Path of this solver's input file inside `input_dir`.
+    pub fn input_path(&self, input_dir: &Path) -> PathBuf {
+        (self.solution.input_path)(input_dir, self.key.part)
+    }
+
+    /// Whether `other` is the other part of the same day reading the very same input, so one parse can serve both.
+    pub fn shares_input(&self, other: &Solver, source: &InputSource) -> bool {
+        let same_solution = std::ptr::eq(self.solution, other.solution);
+        return match source {
+            InputSource::Directory(input_dir) => same_solution && self.input_path(input_dir) == other.input_path(input_dir),
+            InputSource::File(_) | InputSource::Text(_) => same_solution
+        }
+    }
+
+    pub fn read<'a>(&self, source: &'a InputSource) -> Result<Cow<'a, str>, DayError> {
+        let raw = match source {
+            InputSource::Directory(input_dir) => read_input(self.input_path(input_dir)).map(Cow::Owned),
+            InputSource::File(path) => read_input(path).map(Cow::Owned),
+            InputSource::Text(text) => Ok(Cow::Borrowed(text.as_str()))
+        };
+
+        return raw.map_err(|e| {
+            DayError::InputError {
+                day: self.key.day,
+                part: self.key.part,
+                source: e
+            }
+        })
+    }
+
+    pub fn parse(&self, raw: &str) -> Result<ParsedInput, DayError> {
+        (self.solution.parse)(raw).map_err(|e| { self.internal_error(e) })
+    }
+
+    pub fn solve_parsed(&self, input: &ParsedInput) -> DayResult {
+        (self.solution.solve)(input, self.key.part).map_err(|e| { self.internal_error(e) })
+    }
+
+    /// The solution's own account of a parsed input, see `DaySolution::describe`.
+    pub fn describe(&self, input: &ParsedInput) -> Option<String> {
+        (self.solution.describe)(input)
+    }
+
+    pub fn solve(&self, source: &InputSource) -> DayResult {
+        self.solve_timed(source, &mut PhaseTimings::default())
+    }
+
+    /// Solves the puzzle, recording how long each phase took in `timings`. Phases that never ran keep a zero duration.
+    pub fn solve_timed(&self, source: &InputSource, timings: &mut PhaseTimings) -> DayResult {
+        let start = Instant::now();
+        let raw = self.read(source)?;
+        timings.read = start.elapsed();
+
+        let start = Instant::now();
+        let input = self.parse(&raw)?;
+        timings.parse = start.elapsed();
+
+        debug!("{} read in {} and parsed in {}", self.key, format_duration(timings.read), format_duration(timings.parse));
+
+        let start = Instant::now();
+        let answer = self.solve_parsed(&input);
+        timings.solve = start.elapsed();
+
+        return answer
+    }
+
+    fn internal_error(&self, error: Box<dyn Error + Send + Sync>) -> DayError {
+        DayError::InternalDayError {
+            day: self.key.day,
+            part: self.key.part,
+            source: error
+        }
+    }
+}
+
+/// Every solution registered with `register_solution!`, keyed by year, day and part.
+pub struct Registry;
+
+impl Registry {
+    pub fn get(year: u16, day: u8, part: Part) -> Option<Solver> {
+        Self::all().into_iter().find(|solver| { solver.key == SolverKey { year, day, part } })
+    }
+
+    /// Both parts of every registered solution, ordered by year, day and part.
+    pub fn all() -> Vec<Solver> {
+        let mut solvers = inventory::iter::<Solution>.into_iter()
+            .flat_map(|solution| {
+                [Part::One, Part::Two].map(|part| {
+                    Solver {
+                        key: SolverKey { year: solution.year, day: solution.day, part },
+                        solution
+                    }
+                })
+            })
+            .collect::<Vec<Solver>>();
+        solvers.sort_by_key(|solver| { solver.key });
+        return solvers
+    }
+}
@@ -0,0 +1,132 @@
+use log::{debug, trace};
+use thiserror::Error;
+use nom::{branch::alt, character::complete::{line_ending, u32}, combinator::{eof, map, peek}, error::context};
+
+use crate::days::{Answer, DaySolution};
+use crate::days::Example;
+use crate::parser::{lines, parse_input, InputError, ParseResult};
+
+use crate::days::registry::register_solution;
+
+pub struct Day1;
+
+const EXAMPLE_INPUT: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+";
+
+impl Day1 {
+    fn find_max_calories(input: &[Vec<u32>]) -> Result<u64, Day1Error> {
+        let calorie_sums = Self::sum_calories(input);
+
+        return calorie_sums.into_iter().max().ok_or(Day1Error::EmptyInputError{})
+    }
+
+    fn find_3_top_calories(input: &[Vec<u32>]) -> Result<u64, Day1Error> {
+    const NUM_ELVES: usize = 3; 
+
+        let mut calorie_sums = Self::sum_calories(input);
+
+        calorie_sums.sort();
+        let (_head, top_3) = calorie_sums.split_at(calorie_sums.len().saturating_sub(NUM_ELVES));
+
+        let sum = top_3.iter().sum();
+        
+        return if sum == 0 {
+            Err(Day1Error::EmptyInputError{})
+        } else {
+            Ok(sum)
+        }
+    }
+
+    fn sum_calories(input: &[Vec<u32>]) -> Vec<u64> {
+        input.iter().enumerate().map(|(index, calorie_list)| {
+            let sum = calorie_list.iter().map(|value| { u64::from(*value) }).sum();
+            debug!("Elf #{} Total Calories = {}", index, sum);
+            return sum
+        }).collect::<Vec<u64>>()
+    }
+}
+
+impl DaySolution for Day1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+    const EXAMPLES: &'static [Example] = &[Example { input: EXAMPLE_INPUT, part1: Some("24000"), part2: Some("45000") }];
+
+    type Input = Vec<Vec<u32>>;
+    type Error = Day1Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let elves = Parser::parse(input)?;
+        debug!("Parsed {} elves", elves.len());
+        return Ok(elves)
+    }
+
+    fn describe(input: &Self::Input) -> Option<String> {
+        let items = input.iter().map(Vec::len).sum::<usize>();
+        Some(format!("{} Elves carrying {} items of food", input.len(), items))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Self::Error> {
+        Self::find_max_calories(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Self::Error> {
+        Self::find_3_top_calories(input).map(Answer::from)
+    }
+}
+
+register_solution!(Day1);
+
+struct Parser;
+
+impl Parser {
+    pub fn parse(input: &str) -> Result<Vec<Vec<u32>>, InputError> {
+        parse_input(input, "Elves' Calories", Self::parse_elves)
+    }
+
+    /// One number of Calories per line, with a blank line after each Elf's food.
+    fn parse_elves(input: &str) -> ParseResult<'_, Vec<Vec<u32>>> {
+        let (rest, calorie_lines) = lines(Self::parse_calories)(input)?;
+
+        let calorie_lists = calorie_lines.split(Option::is_none)
+            .filter(|calorie_list| { !calorie_list.is_empty() })
+            .map(|calorie_list| { calorie_list.iter().flatten().copied().collect::<Vec<u32>>() })
+            .collect::<Vec<Vec<u32>>>();
+
+        return Ok((rest, calorie_lists))
+    }
+
+    /// A line holding the Calories of one item of food, or `None` for a blank line between Elves.
+    fn parse_calories(input: &str) -> ParseResult<'_, Option<u32>> {
+        let calories = map(u32, Some);
+        let blank_line = map(peek(alt((line_ending, eof))), |_| { None });
+        let mut calories_or_blank_line = context("a number of Calories or a blank line", alt((calories, blank_line)));
+
+        let (rest, calories) = calories_or_blank_line(input)?;
+        trace!("Parsed {:?}", calories);
+        return Ok((rest, calories))
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum Day1Error {
+    #[error(transparent)]
+    ParseInputError {
+        #[from]
+        source: InputError
+    },
+    #[error("Input was somehow empty")]
+    EmptyInputError {}
+}
\ No newline at end of file
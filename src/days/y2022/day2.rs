@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+
+use log::debug;
+use nom::{sequence::separated_pair, character::complete::{char, one_of}, combinator::map_res, error::context};
+use thiserror::Error;
+
+use crate::parser::{lines, parse_input, InputError, ParseResult};
+
+use crate::days::{Answer, DaySolution, Example, registry::register_solution};
+
+pub(super) struct Day2;
+
+const EXAMPLE_INPUT: &str = "A Y
+B X
+C Z
+";
+
+impl Day2 {
+    fn calculate_score(input: &[RPSRound]) -> Result<u64, Day2Error> {
+        return Ok(input.iter().map(|round| {
+            let option_score = u64::from(RPS::get_value(&round.player_option));
+            let round_result = RPS::get_result(round);
+            let result_score = u64::from(RPSResult::get_value(&round_result));
+            
+            return option_score + result_score
+        }).sum())
+    }
+
+    fn calculate_strategy_score(input: &[RPSRound]) -> Result<u64, Day2Error> {
+        let result_map: HashMap<RPS, HashMap<RPSResult, RPS>> = HashMap::from(
+            [
+                (
+                    RPS::Rock, 
+                    HashMap::from([
+                        (RPSResult::Win, RPS::Paper),
+                        (RPSResult::Draw, RPS::Rock),
+                        (RPSResult::Loss, RPS::Scissors)
+                    ])
+                ),
+                (
+                    RPS::Paper,
+                    HashMap::from([
+                        (RPSResult::Win, RPS::Scissors),
+                        (RPSResult::Draw, RPS::Paper),
+                        (RPSResult::Loss, RPS::Rock)
+                    ])
+                ),
+                (
+                    RPS::Scissors,
+                    HashMap::from([
+                        (RPSResult::Win, RPS::Rock),
+                        (RPSResult::Draw, RPS::Scissors),
+                        (RPSResult::Loss, RPS::Paper)
+                    ])
+                )
+            ]
+        );
+
+        return input.iter()
+            .map(|round| {
+                let outcome = round.outcome;
+                let player_option = result_map
+                    .get(&round.opponent_option)
+                    .ok_or(Day2Error::RPSMapAccessError{ key: round.opponent_option })?
+                    .get(&outcome)
+                    .ok_or(Day2Error::RPSResultMapAccessError{ key: outcome })?;
+
+                let outcome_points = u64::from(RPSResult::get_value(&outcome));
+                let option_points = u64::from(RPS::get_value(player_option));
+
+                return Ok(option_points + outcome_points)
+            })
+            .sum();
+    }
+}
+
+impl DaySolution for Day2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+
+    const EXAMPLES: &'static [Example] = &[Example { input: EXAMPLE_INPUT, part1: Some("15"), part2: Some("12") }];
+
+    type Input = Vec<RPSRound>;
+
+    type Error = Day2Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let rounds = Parser::parse(input)?;
+        debug!("Parsed {} rounds", rounds.len());
+        return Ok(rounds)
+    }
+
+    fn describe(input: &Self::Input) -> Option<String> {
+        Some(format!("{} rounds of Rock Paper Scissors", input.len()))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Self::Error> {
+        return Self::calculate_score(input)
+        .map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Self::Error> {
+        return Self::calculate_strategy_score(input)
+        .map(Answer::from)
+    }
+}
+
+register_solution!(Day2);
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub(super) enum RPS {
+    Rock,
+    Paper,
+    Scissors
+}
+
+impl RPS {
+    pub fn from_char(symbol: &char) -> Option<Self> {
+        match symbol {
+            'A' | 'X' => Some(RPS::Rock),
+            'B' | 'Y' => Some(RPS::Paper),
+            'C' | 'Z' => Some(RPS::Scissors),
+            _ => None
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn to_player_symbol(symbol: &RPS) -> char {
+        match symbol {
+            RPS::Rock => 'X',
+            RPS::Paper => 'Y',
+            RPS::Scissors => 'Z'
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn to_opponent_symbol(symbol: &RPS) -> char {
+        match symbol {
+            RPS::Rock => 'A',
+            RPS::Paper => 'B',
+            RPS::Scissors => 'C'
+        }
+    }
+
+    pub fn get_value(symbol: &RPS) -> u32 {
+        match symbol {
+            RPS::Rock => 1,
+            RPS::Paper => 2,
+            RPS::Scissors => 3
+        }
+    }
+
+    pub fn get_result(round: &RPSRound) -> RPSResult {
+        match (round.player_option, round.opponent_option) {
+            (RPS::Rock, RPS::Scissors) | 
+            (RPS::Paper, RPS::Rock) |
+            (RPS::Scissors, RPS::Paper) => RPSResult::Win,
+            (RPS::Rock, RPS::Paper) |
+            (RPS::Paper, RPS::Scissors) |
+            (RPS::Scissors, RPS::Rock) => RPSResult::Loss,
+            (RPS::Rock, RPS::Rock) |
+            (RPS::Paper, RPS::Paper) |
+            (RPS::Scissors, RPS::Scissors) => RPSResult::Draw
+        }
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, Debug, Clone, Copy)]
+pub(super) enum RPSResult {
+    Win,
+    Loss,
+    Draw
+}
+
+impl RPSResult {
+    pub fn get_value(result: &RPSResult) -> u32 {
+        match result {
+            RPSResult::Win => 6,
+            RPSResult::Loss => 0,
+            RPSResult::Draw => 3
+        }
+    }
+
+    pub fn from_char(symbol: &char) -> Option<Self> {
+        Some(match symbol {
+            'X' => RPSResult::Loss,
+            'Y' => RPSResult::Draw,
+            'Z' => RPSResult::Win,
+            _ => return None
+        })
+    }
+}
+
+/// One line of the strategy guide. The second column is read as the option to play in part 1 and
+/// as the outcome to aim for in part 2, so both readings are kept.
+pub(super) struct RPSRound {
+    pub player_option: RPS,
+    pub opponent_option: RPS,
+    pub outcome: RPSResult
+}
+
+struct Parser;
+
+impl Parser {
+    pub fn parse(input: &str) -> Result<Vec<RPSRound>, InputError> {
+        parse_input(input, "rounds of RPS", Self::parse_rounds)
+    }
+
+    fn parse_rounds(input: &str) -> ParseResult<'_, Vec<RPSRound>> {
+        lines(Self::parse_round)(input)
+    }
+
+    fn parse_round(input: &str) -> ParseResult<'_, RPSRound> {
+        let player_option = context("X, Y or Z", one_of("XYZ"));
+        let opponent_option = context("A, B or C", one_of("ABC"));
+        let round_delimiter = context("a space", char(' '));
+        let separated_options = separated_pair(opponent_option, round_delimiter, player_option);
+        let mut converted_round = map_res(separated_options, Self::round_from_chars);
+
+        converted_round(input)
+    }
+
+    fn rps_from_char(input: char) -> Result<RPS, nom::error::Error<char>> {
+        RPS::from_char(&input)
+        .ok_or(nom::error::Error{ 
+            input, 
+            code: nom::error::ErrorKind::Char 
+        })
+    }
+
+    fn round_from_chars(input: (char, char)) -> Result<RPSRound, nom::error::Error<char>> {
+        Ok(RPSRound {
+            player_option: Self::rps_from_char(input.1)?,
+            opponent_option: Self::rps_from_char(input.0)?,
+            outcome: Self::outcome_from_char(input.1)?
+        })
+    }
+
+    fn outcome_from_char(input: char) -> Result<RPSResult, nom::error::Error<char>> {
+        RPSResult::from_char(&input)
+        .ok_or(nom::error::Error{ 
+            input, 
+            code: nom::error::ErrorKind::Char 
+        })
+    }
+}
+
+#[derive(Debug, Error)]
+pub(super) enum Day2Error {
+    #[error(transparent)]
+    ParseInputError {
+        #[from]
+        source: InputError
+    },
+    #[error("Get() returned None when accessing map of options to outcomes with Key: {key:?}")]
+    RPSMapAccessError {
+        key: RPS
+    },
+    #[error("Get() returned None when accessing map of outcomes to options with Key: {key:?}")]
+    RPSResultMapAccessError {
+        key: RPSResult
+    }
+}
\ No newline at end of file
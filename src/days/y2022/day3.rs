@@ -0,0 +1,166 @@
+use log::{debug, trace};
+use nom::{character::complete::alpha1, combinator::{map, verify}, error::context};
+use thiserror::Error;
+
+use crate::parser::{lines, parse_input, InputError, ParseResult};
+
+use crate::days::{Answer, DaySolution, Example, registry::register_solution};
+
+pub(super) struct Day3;
+
+const EXAMPLE_INPUT: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+/// Elves are split into groups of three, each group sharing one badge item.
+const GROUP_SIZE: usize = 3;
+
+impl Day3 {
+    fn sum_misplaced_priorities(input: &[Rucksack]) -> Result<u64, Day3Error> {
+        input.iter().enumerate().map(|(index, rucksack)| {
+            let shared = rucksack.first.intersection(rucksack.second);
+            trace!("Rucksack #{} shares {:?}", index, shared);
+            shared.single().map(u64::from).ok_or(Day3Error::SharedItemError { rucksack: index + 1, count: shared.len() })
+        }).sum()
+    }
+
+    fn sum_badge_priorities(input: &[Rucksack]) -> Result<u64, Day3Error> {
+        if !input.len().is_multiple_of(GROUP_SIZE) {
+            return Err(Day3Error::GroupSizeError { rucksacks: input.len() })
+        }
+
+        return input.chunks(GROUP_SIZE).enumerate().map(|(index, group)| {
+            let badge = group.iter().map(Rucksack::items).reduce(ItemSet::intersection).unwrap_or_default();
+            debug!("Group #{} carries {:?}", index, badge);
+            badge.single().map(u64::from).ok_or(Day3Error::BadgeError { group: index + 1, count: badge.len() })
+        }).sum()
+    }
+}
+
+impl DaySolution for Day3 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+    const EXAMPLES: &'static [Example] = &[Example { input: EXAMPLE_INPUT, part1: Some("157"), part2: Some("70") }];
+
+    type Input = Vec<Rucksack>;
+
+    type Error = Day3Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let rucksacks = Parser::parse(input)?;
+        debug!("Parsed {} rucksacks", rucksacks.len());
+        return Ok(rucksacks)
+    }
+
+    fn describe(input: &Self::Input) -> Option<String> {
+        Some(format!("{} rucksacks in {} groups of {}", input.len(), input.len() / GROUP_SIZE, GROUP_SIZE))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Self::Error> {
+        Self::sum_misplaced_priorities(input).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Self::Error> {
+        Self::sum_badge_priorities(input).map(Answer::from)
+    }
+}
+
+register_solution!(Day3);
+
+/// Item types, one bit per priority: `a` to `z` are 1 to 26 and `A` to `Z` are 27 to 52.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub(super) struct ItemSet(u64);
+
+impl ItemSet {
+    fn from_items(items: &str) -> Self {
+        ItemSet(items.bytes().filter_map(Self::priority).fold(0, |set, priority| { set | 1 << priority }))
+    }
+
+    fn priority(item: u8) -> Option<u8> {
+        match item {
+            b'a'..=b'z' => Some(item - b'a' + 1),
+            b'A'..=b'Z' => Some(item - b'A' + 27),
+            _ => None
+        }
+    }
+
+    fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Priority of the only item type in the set, or `None` if there are none or several.
+    fn single(self) -> Option<u8> {
+        if self.len() == 1 { Some(self.0.trailing_zeros() as u8) } else { None }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(super) struct Rucksack {
+    first: ItemSet,
+    second: ItemSet
+}
+
+impl Rucksack {
+    fn items(&self) -> ItemSet {
+        self.first.union(self.second)
+    }
+}
+
+struct Parser;
+
+impl Parser {
+    pub fn parse(input: &str) -> Result<Vec<Rucksack>, InputError> {
+        parse_input(input, "rucksacks", Self::parse_rucksacks)
+    }
+
+    fn parse_rucksacks(input: &str) -> ParseResult<'_, Vec<Rucksack>> {
+        lines(Self::parse_rucksack)(input)
+    }
+
+    /// A line of letters split down the middle into the two compartments.
+    fn parse_rucksack(input: &str) -> ParseResult<'_, Rucksack> {
+        let items = context("letters a-z or A-Z", alpha1);
+        let even_items = context("an even number of items", verify(items, |items: &str| { items.len().is_multiple_of(2) }));
+        let mut rucksack = map(even_items, |items: &str| {
+            let (first, second) = items.split_at(items.len() / 2);
+            return Rucksack { first: ItemSet::from_items(first), second: ItemSet::from_items(second) }
+        });
+
+        rucksack(input)
+    }
+}
+
+#[derive(Debug, Error)]
+pub(super) enum Day3Error {
+    #[error(transparent)]
+    ParseInputError {
+        #[from]
+        source: InputError
+    },
+    #[error("Rucksack {rucksack} has {count} item types in both compartments instead of exactly one")]
+    SharedItemError {
+        rucksack: usize,
+        count: u32
+    },
+    #[error("{rucksacks} rucksacks cannot be split into groups of three")]
+    GroupSizeError {
+        rucksacks: usize
+    },
+    #[error("Group {group} has {count} item types in common instead of exactly one badge")]
+    BadgeError {
+        group: usize,
+        count: u32
+    }
+}
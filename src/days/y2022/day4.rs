@@ -0,0 +1,137 @@
+use log::{debug, trace};
+use nom::{character::complete::{char, u32}, error::context, sequence::separated_pair};
+use thiserror::Error;
+
+use crate::parser::{lines, parse_input, InputError, ParseResult};
+
+use crate::days::{Answer, DaySolution, Example, registry::register_solution};
+
+pub(super) struct Day4;
+
+const EXAMPLE_INPUT: &str = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+";
+
+impl Day4 {
+    fn count_pairs(input: &[AssignmentPair], condition: fn(&SectionRange, &SectionRange) -> bool) -> u64 {
+        input.iter().filter(|pair| { condition(&pair.first, &pair.second) }).count() as u64
+    }
+
+    fn either_contains(first: &SectionRange, second: &SectionRange) -> bool {
+        first.contains(second) || second.contains(first)
+    }
+}
+
+impl DaySolution for Day4 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+    const EXAMPLES: &'static [Example] = &[Example { input: EXAMPLE_INPUT, part1: Some("2"), part2: Some("4") }];
+
+    type Input = Vec<AssignmentPair>;
+
+    type Error = Day4Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let pairs = Parser::parse(input)?.into_iter().enumerate().map(|(index, (first, second))| {
+            let pair = index + 1;
+            return Ok(AssignmentPair { first: SectionRange::new(pair, first)?, second: SectionRange::new(pair, second)? })
+        }).collect::<Result<Vec<AssignmentPair>, Day4Error>>()?;
+
+        debug!("Parsed {} assignment pairs", pairs.len());
+        return Ok(pairs)
+    }
+
+    fn describe(input: &Self::Input) -> Option<String> {
+        let sections = input.iter().flat_map(|pair| { [pair.first.end, pair.second.end] }).max().unwrap_or_default();
+        Some(format!("{} pairs of Elves cleaning sections up to {}", input.len(), sections))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(Answer::from(Self::count_pairs(input, Self::either_contains)))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(Answer::from(Self::count_pairs(input, SectionRange::overlaps)))
+    }
+}
+
+register_solution!(Day4);
+
+/// The sections from `start` to `end`, both included.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(super) struct SectionRange {
+    start: u32,
+    end: u32
+}
+
+impl SectionRange {
+    /// Checks that the `(start, end)` read for pair number `pair` does not run backwards.
+    fn new(pair: usize, (start, end): (u32, u32)) -> Result<Self, Day4Error> {
+        if start > end {
+            return Err(Day4Error::ReversedRangeError { pair, start, end })
+        }
+
+        return Ok(SectionRange { start, end })
+    }
+
+    fn contains(&self, other: &SectionRange) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    fn overlaps(&self, other: &SectionRange) -> bool {
+        let overlaps = self.start <= other.end && other.start <= self.end;
+        trace!("{:?} and {:?} overlap: {}", self, other, overlaps);
+        return overlaps
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(super) struct AssignmentPair {
+    first: SectionRange,
+    second: SectionRange
+}
+
+type RawRange = (u32, u32);
+
+struct Parser;
+
+impl Parser {
+    pub fn parse(input: &str) -> Result<Vec<(RawRange, RawRange)>, InputError> {
+        parse_input(input, "assignment pairs", Self::parse_pairs)
+    }
+
+    fn parse_pairs(input: &str) -> ParseResult<'_, Vec<(RawRange, RawRange)>> {
+        lines(Self::parse_pair)(input)
+    }
+
+    fn parse_pair(input: &str) -> ParseResult<'_, (RawRange, RawRange)> {
+        separated_pair(Self::parse_range, context("',' between the two ranges", char(',')), Self::parse_range)(input)
+    }
+
+    /// A range of sections written `start-end`, checked to run forwards only once parsed.
+    fn parse_range(input: &str) -> ParseResult<'_, RawRange> {
+        let start = context("a section number", u32);
+        let end = context("a section number", u32);
+
+        separated_pair(start, context("'-' between two section numbers", char('-')), end)(input)
+    }
+}
+
+#[derive(Debug, Error)]
+pub(super) enum Day4Error {
+    #[error(transparent)]
+    ParseInputError {
+        #[from]
+        source: InputError
+    },
+    #[error("Pair {pair} has the range {start}-{end}, which ends before it starts")]
+    ReversedRangeError {
+        pair: usize,
+        start: u32,
+        end: u32
+    }
+}
@@ -0,0 +1,266 @@
+use log::{debug, trace};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, line_ending, satisfy, space0, space1, u32},
+    combinator::{cut, map, not, value},
+    error::context,
+    multi::{many0, many1, separated_list1},
+    sequence::{delimited, pair, preceded, terminated, tuple}
+};
+use thiserror::Error;
+
+use crate::parser::{lines, parse_input, InputError, ParseResult};
+
+use crate::days::{Answer, DaySolution, Example, registry::register_solution};
+
+pub(super) struct Day5;
+
+const EXAMPLE_INPUT: &str = "    [D]
+[N] [C]
+[Z] [M] [P]
+ 1   2   3
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+
+impl Day5 {
+    /// Runs every step of the procedure with `crane` and reads off the crate on top of each stack.
+    fn top_crates(procedure: &Procedure, crane: Crane) -> Result<String, Day5Error> {
+        let mut stacks = procedure.stacks.clone();
+
+        for (index, step) in procedure.steps.iter().enumerate() {
+            let height = stacks[step.from].len();
+            if step.count > height {
+                return Err(Day5Error::NotEnoughCratesError { step: index + 1, count: step.count, stack: step.from + 1, height })
+            }
+            if step.from == step.to {
+                continue
+            }
+
+            let mut lifted = stacks[step.from].split_off(height - step.count);
+            if crane == Crane::CrateMover9000 {
+                lifted.reverse();
+            }
+            trace!("Step {}: {:?} moves {:?} from {} to {}", index + 1, crane, lifted, step.from + 1, step.to + 1);
+            stacks[step.to].extend(lifted);
+        }
+
+        return stacks.iter().enumerate()
+            .map(|(index, stack)| { stack.last().copied().ok_or(Day5Error::EmptyStackError { stack: index + 1 }) })
+            .collect()
+    }
+}
+
+impl DaySolution for Day5 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+    const EXAMPLES: &'static [Example] = &[Example { input: EXAMPLE_INPUT, part1: Some("CMZ"), part2: Some("MCD") }];
+    const POSITIONAL_INPUT: bool = true;
+
+    type Input = Procedure;
+
+    type Error = Day5Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let procedure = Procedure::from_drawing(Parser::parse(input)?)?;
+        debug!("Parsed {} stacks and {} steps", procedure.stacks.len(), procedure.steps.len());
+        return Ok(procedure)
+    }
+
+    fn describe(input: &Self::Input) -> Option<String> {
+        let crates = input.stacks.iter().map(Vec::len).sum::<usize>();
+        Some(format!("{} stacks of {} crates, rearranged in {} steps", input.stacks.len(), crates, input.steps.len()))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Self::Error> {
+        Self::top_crates(input, Crane::CrateMover9000).map(Answer::from)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Self::Error> {
+        Self::top_crates(input, Crane::CrateMover9001).map(Answer::from)
+    }
+}
+
+register_solution!(Day5);
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Crane {
+    /// Moves crates one at a time, so the crates moved together end up in reverse order.
+    CrateMover9000,
+    /// Moves all the crates of a step at once, keeping their order.
+    CrateMover9001
+}
+
+/// Moves `count` crates from the stack at index `from` to the one at index `to`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(super) struct Step {
+    count: usize,
+    from: usize,
+    to: usize
+}
+
+/// The starting stacks, bottom crate first, and the steps rearranging them.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(super) struct Procedure {
+    stacks: Vec<Vec<char>>,
+    steps: Vec<Step>
+}
+
+impl Procedure {
+    /// Stacks the crates of the drawing's rows column by column, checking they all sit on a numbered stack
+    /// and on top of another crate, and that every step moves between stacks that exist.
+    fn from_drawing(drawing: Drawing) -> Result<Self, Day5Error> {
+        for (index, &label) in drawing.labels.iter().enumerate() {
+            if label as usize != index + 1 {
+                return Err(Day5Error::StackNumberError { position: index + 1, label })
+            }
+        }
+
+        let mut stacks = vec![Vec::new(); drawing.labels.len()];
+        for (row, cells) in drawing.rows.iter().enumerate().rev() {
+            for (stack, cell) in cells.iter().enumerate() {
+                let Some(item) = *cell else {
+                    continue
+                };
+                if stack >= stacks.len() {
+                    return Err(Day5Error::UnnumberedStackError { line: row + 1, item, stack: stack + 1, stacks: stacks.len() })
+                }
+                if stacks[stack].len() != drawing.rows.len() - row - 1 {
+                    return Err(Day5Error::FloatingCrateError { line: row + 1, item, stack: stack + 1 })
+                }
+                stacks[stack].push(item);
+            }
+        }
+
+        let stack_index = |index: usize, stack: u32| {
+            match stack as usize {
+                number if number == 0 || number > stacks.len() => Err(Day5Error::UnknownStackError { step: index + 1, stack, stacks: stacks.len() }),
+                number => Ok(number - 1)
+            }
+        };
+        let steps = drawing.steps.iter().enumerate().map(|(index, step)| {
+            return Ok(Step { count: step.count as usize, from: stack_index(index, step.from)?, to: stack_index(index, step.to)? })
+        }).collect::<Result<Vec<Step>, Day5Error>>()?;
+
+        return Ok(Procedure { stacks, steps })
+    }
+}
+
+/// A step as written, with stacks still numbered from 1.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct RawStep {
+    count: u32,
+    from: u32,
+    to: u32
+}
+
+/// The input as drawn: each row of crates from the top down, one cell per column, then the stack numbers and steps.
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct Drawing {
+    rows: Vec<Vec<Option<char>>>,
+    labels: Vec<u32>,
+    steps: Vec<RawStep>
+}
+
+struct Parser;
+
+impl Parser {
+    pub fn parse(input: &str) -> Result<Drawing, InputError> {
+        parse_input(input, "the crate drawing", Self::parse_drawing)
+    }
+
+    fn parse_drawing(input: &str) -> ParseResult<'_, Drawing> {
+        // Once a line is known not to be the stack numbers it has to be a row of crates, so its errors are final.
+        let row = terminated(Self::parse_row, context("the end of the row", line_ending));
+        let rows = many1(preceded(not(Self::parse_labels), cut(row)));
+        let labels = terminated(Self::parse_labels, line_ending);
+        let blank_line = context("a blank line after the stack numbers", line_ending);
+        let steps = lines(Self::parse_step);
+
+        map(tuple((rows, labels, blank_line, steps)), |(rows, labels, _, steps)| { Drawing { rows, labels, steps } })(input)
+    }
+
+    /// Cells four characters apart, one space between them. Rows may stop after their last crate.
+    fn parse_row(input: &str) -> ParseResult<'_, Vec<Option<char>>> {
+        let cells = pair(Self::parse_cell, many0(preceded(char(' '), cut(Self::parse_cell))));
+
+        map(cells, |(first, mut rest)| {
+            rest.insert(0, first);
+            return rest
+        })(input)
+    }
+
+    /// `[A]` for a crate or three spaces for none.
+    fn parse_cell(input: &str) -> ParseResult<'_, Option<char>> {
+        let item = context("a crate letter A-Z", satisfy(|c| { c.is_ascii_uppercase() }));
+        let crate_cell = map(delimited(char('['), cut(item), cut(context("']'", char(']')))), Some);
+        let empty_cell = value(None, tag("   "));
+
+        context("a crate like [A] or three spaces", alt((crate_cell, empty_cell)))(input)
+    }
+
+    fn parse_labels(input: &str) -> ParseResult<'_, Vec<u32>> {
+        delimited(space0, separated_list1(space1, context("a stack number", u32)), space0)(input)
+    }
+
+    fn parse_step(input: &str) -> ParseResult<'_, RawStep> {
+        let step = tuple((
+            context("'move'", tag("move ")),
+            context("a number of crates", u32),
+            context("' from '", tag(" from ")),
+            context("a stack number", u32),
+            context("' to '", tag(" to ")),
+            context("a stack number", u32)
+        ));
+
+        map(step, |(_, count, _, from, _, to)| { RawStep { count, from, to } })(input)
+    }
+}
+
+#[derive(Debug, Error)]
+pub(super) enum Day5Error {
+    #[error(transparent)]
+    ParseInputError {
+        #[from]
+        source: InputError
+    },
+    #[error("Stack {position} from the left is numbered {label}, stacks have to be numbered 1, 2, 3 and so on")]
+    StackNumberError {
+        position: usize,
+        label: u32
+    },
+    #[error("Crate [{item}] on line {line} is in column {stack}, but there are only {stacks} numbered stacks")]
+    UnnumberedStackError {
+        line: usize,
+        item: char,
+        stack: usize,
+        stacks: usize
+    },
+    #[error("Crate [{item}] on line {line} is floating above an empty space in stack {stack}")]
+    FloatingCrateError {
+        line: usize,
+        item: char,
+        stack: usize
+    },
+    #[error("Step {step} uses stack {stack}, but there are only stacks 1 to {stacks}")]
+    UnknownStackError {
+        step: usize,
+        stack: u32,
+        stacks: usize
+    },
+    #[error("Step {step} moves {count} crates from stack {stack}, which only holds {height}")]
+    NotEnoughCratesError {
+        step: usize,
+        count: usize,
+        stack: usize,
+        height: usize
+    },
+    #[error("Stack {stack} ends up empty, so it has no crate on top")]
+    EmptyStackError {
+        stack: usize
+    }
+}
@@ -0,0 +1,133 @@
+use log::{debug, trace};
+use nom::{bytes::complete::take_while1, combinator::map, error::context};
+use thiserror::Error;
+
+use crate::parser::{lines, parse_input, InputError, ParseResult};
+
+use crate::days::{Answer, DaySolution, Example, registry::register_solution};
+
+pub(super) struct Day6;
+
+const EXAMPLE_INPUT: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb
+bvwbjplbgvbhsrlpgdmjqwftvncz
+nppdvjthqldpwncqszvftbrmjlhg
+nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg
+zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw
+";
+
+/// Distinct characters in a row that mark the start of a packet.
+const PACKET_MARKER_SIZE: usize = 4;
+/// Distinct characters in a row that mark the start of a message.
+const MESSAGE_MARKER_SIZE: usize = 14;
+
+impl Day6 {
+    /// How many characters of `stream` have been read once the last `size` of them are all different.
+    /// Keeps a count of each character in the window so every character is looked at only twice.
+    fn marker_end(stream: &[u8], size: usize) -> Option<usize> {
+        let mut counts = [0usize; 256];
+        let mut distinct = 0;
+
+        for (index, &character) in stream.iter().enumerate() {
+            counts[usize::from(character)] += 1;
+            if counts[usize::from(character)] == 1 {
+                distinct += 1;
+            }
+            if index >= size {
+                let dropped = usize::from(stream[index - size]);
+                counts[dropped] -= 1;
+                if counts[dropped] == 0 {
+                    distinct -= 1;
+                }
+            }
+            if distinct == size {
+                return Some(index + 1)
+            }
+        }
+
+        return None
+    }
+
+    /// Finds the marker of each stream. A single stream answers with a number, several with one row per stream.
+    fn find_markers(input: &[Vec<u8>], size: usize) -> Result<Answer, Day6Error> {
+        let markers = input.iter().enumerate().map(|(index, stream)| {
+            let marker = Self::marker_end(stream, size).ok_or(Day6Error::NoMarkerError { stream: index + 1, size })?;
+            trace!("Stream #{} has a marker of {} ending at {}", index + 1, size, marker);
+            return Ok(marker)
+        }).collect::<Result<Vec<usize>, Day6Error>>()?;
+
+        return match markers.as_slice() {
+            [marker] => Ok(Answer::from(*marker)),
+            _ => Ok(Answer::grid(markers.iter().map(usize::to_string)))
+        }
+    }
+}
+
+impl DaySolution for Day6 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+    const EXAMPLES: &'static [Example] = &[
+        Example { input: EXAMPLE_INPUT, part1: Some("7\n5\n6\n10\n11"), part2: Some("19\n23\n23\n29\n26") },
+        Example { input: "bvwbjplbgvbhsrlpgdmjqwftvncz\n", part1: Some("5"), part2: Some("23") }
+    ];
+
+    type Input = Vec<Vec<u8>>;
+
+    type Error = Day6Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let streams = Parser::parse(input)?;
+        debug!("Parsed {} datastreams", streams.len());
+        return Ok(streams)
+    }
+
+    fn describe(input: &Self::Input) -> Option<String> {
+        let characters = input.iter().map(Vec::len).sum::<usize>();
+        match input.len() {
+            1 => Some(format!("A datastream of {} characters", characters)),
+            streams => Some(format!("{} datastreams of {} characters in all", streams, characters))
+        }
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Self::Error> {
+        Self::find_markers(input, PACKET_MARKER_SIZE)
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Self::Error> {
+        Self::find_markers(input, MESSAGE_MARKER_SIZE)
+    }
+}
+
+register_solution!(Day6);
+
+struct Parser;
+
+impl Parser {
+    pub fn parse(input: &str) -> Result<Vec<Vec<u8>>, InputError> {
+        parse_input(input, "datastreams", Self::parse_streams)
+    }
+
+    fn parse_streams(input: &str) -> ParseResult<'_, Vec<Vec<u8>>> {
+        lines(Self::parse_stream)(input)
+    }
+
+    /// One datastream per line, written in lowercase letters.
+    fn parse_stream(input: &str) -> ParseResult<'_, Vec<u8>> {
+        let letters = context("lowercase letters", take_while1(|c: char| { c.is_ascii_lowercase() }));
+
+        map(letters, |stream: &str| { stream.as_bytes().to_vec() })(input)
+    }
+}
+
+#[derive(Debug, Error)]
+pub(super) enum Day6Error {
+    #[error(transparent)]
+    ParseInputError {
+        #[from]
+        source: InputError
+    },
+    #[error("Datastream {stream} never has {size} different characters in a row")]
+    NoMarkerError {
+        stream: usize,
+        size: usize
+    }
+}
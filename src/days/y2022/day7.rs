@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use log::{debug, trace};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_till1},
+    character::complete::{char, u64},
+    combinator::{map, value},
+    error::context,
+    sequence::{preceded, separated_pair}
+};
+use thiserror::Error;
+
+use crate::parser::{lines, parse_input, InputError, ParseResult};
+
+use crate::days::{Answer, DaySolution, Example, registry::register_solution};
+
+pub(super) struct Day7;
+
+const EXAMPLE_INPUT: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+";
+
+/// Directories up to this size count towards part 1.
+const SMALL_DIRECTORY_SIZE: u64 = 100000;
+const DISK_SIZE: u64 = 70000000;
+/// Free space the update needs.
+const UPDATE_SIZE: u64 = 30000000;
+
+impl Day7 {
+    fn sum_small_directories(input: &Filesystem) -> u64 {
+        input.directories.iter().map(|directory| { directory.size }).filter(|&size| { size <= SMALL_DIRECTORY_SIZE }).sum()
+    }
+
+    fn smallest_directory_to_delete(input: &Filesystem) -> Result<u64, Day7Error> {
+        let free = DISK_SIZE.saturating_sub(input.directories[Filesystem::ROOT].size);
+        if free >= UPDATE_SIZE {
+            return Err(Day7Error::EnoughSpaceError { free })
+        }
+
+        let needed = UPDATE_SIZE - free;
+        debug!("{} free, {} more needed", free, needed);
+
+        // The root directory is always big enough, so there is at least one candidate.
+        return Ok(input.directories.iter().map(|directory| { directory.size }).filter(|&size| { size >= needed }).min().unwrap_or_default())
+    }
+}
+
+impl DaySolution for Day7 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+    const EXAMPLES: &'static [Example] = &[Example { input: EXAMPLE_INPUT, part1: Some("95437"), part2: Some("24933642") }];
+
+    type Input = Filesystem;
+
+    type Error = Day7Error;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        let filesystem = Filesystem::from_transcript(&Parser::parse(input)?)?;
+        debug!("Parsed {} directories", filesystem.directories.len());
+        return Ok(filesystem)
+    }
+
+    fn describe(input: &Self::Input) -> Option<String> {
+        let files = input.directories.iter().map(|directory| { directory.files.len() }).sum::<usize>();
+        let size = input.directories[Filesystem::ROOT].size;
+        Some(format!("{} directories holding {} files of {} bytes in all", input.directories.len(), files, size))
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, Self::Error> {
+        Ok(Answer::from(Self::sum_small_directories(input)))
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, Self::Error> {
+        Self::smallest_directory_to_delete(input).map(Answer::from)
+    }
+}
+
+register_solution!(Day7);
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(super) struct Directory {
+    name: String,
+    parent: Option<usize>,
+    children: HashMap<String, usize>,
+    files: HashMap<String, u64>,
+    /// Total size of every file in this directory and the directories below it.
+    size: u64
+}
+
+impl Directory {
+    fn new(name: &str, parent: Option<usize>) -> Self {
+        Directory { name: name.to_string(), parent, children: HashMap::new(), files: HashMap::new(), size: 0 }
+    }
+}
+
+/// The directory tree the transcript explored. Directories are kept in the order they were first listed,
+/// so every directory comes after its parent.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(super) struct Filesystem {
+    directories: Vec<Directory>
+}
+
+impl Filesystem {
+    const ROOT: usize = 0;
+
+    /// Replays the transcript, only allowing `cd` into directories an earlier `ls` listed.
+    fn from_transcript(transcript: &[TerminalLine]) -> Result<Self, Day7Error> {
+        let mut filesystem = Filesystem { directories: vec![Directory::new("", None)] };
+        let mut current = Self::ROOT;
+        let mut listing = false;
+
+        for (index, terminal_line) in transcript.iter().enumerate() {
+            let line = index + 1;
+            match terminal_line {
+                TerminalLine::ChangeDirectory(Target::Root) => {
+                    current = Self::ROOT;
+                },
+                TerminalLine::ChangeDirectory(Target::Parent) => {
+                    current = filesystem.directories[current].parent.ok_or(Day7Error::AboveRootError { line })?;
+                },
+                TerminalLine::ChangeDirectory(Target::Child(name)) => {
+                    current = *filesystem.directories[current].children.get(name).ok_or_else(|| {
+                        Day7Error::UnknownDirectoryError { line, name: name.clone(), path: filesystem.path(current) }
+                    })?;
+                },
+                TerminalLine::List => {},
+                TerminalLine::Directory(_) | TerminalLine::File { .. } if !listing => {
+                    return Err(Day7Error::UnexpectedOutputError { line })
+                },
+                TerminalLine::Directory(name) => {
+                    if !filesystem.directories[current].children.contains_key(name) {
+                        let child = filesystem.directories.len();
+                        filesystem.directories.push(Directory::new(name, Some(current)));
+                        filesystem.directories[current].children.insert(name.clone(), child);
+                    }
+                },
+                TerminalLine::File { size, name } => {
+                    filesystem.directories[current].files.insert(name.clone(), *size);
+                }
+            }
+            listing = matches!(terminal_line, TerminalLine::List | TerminalLine::Directory(_) | TerminalLine::File { .. });
+        }
+
+        filesystem.total_sizes();
+        return Ok(filesystem)
+    }
+
+    /// Adds up each directory's size, children before their parents.
+    fn total_sizes(&mut self) {
+        for index in (0..self.directories.len()).rev() {
+            let directory = &mut self.directories[index];
+            directory.size += directory.files.values().sum::<u64>();
+
+            let size = directory.size;
+            trace!("{} holds {} bytes", self.path(index), size);
+            if let Some(parent) = self.directories[index].parent {
+                self.directories[parent].size += size;
+            }
+        }
+    }
+
+    fn path(&self, index: usize) -> String {
+        let directory = &self.directories[index];
+        match directory.parent {
+            None => String::from("/"),
+            Some(Self::ROOT) => format!("/{}", directory.name),
+            Some(parent) => format!("{}/{}", self.path(parent), directory.name)
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum Target {
+    Root,
+    Parent,
+    Child(String)
+}
+
+/// One line of the terminal: either a command or a line of what `ls` printed.
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum TerminalLine {
+    ChangeDirectory(Target),
+    List,
+    Directory(String),
+    File {
+        size: u64,
+        name: String
+    }
+}
+
+struct Parser;
+
+impl Parser {
+    pub fn parse(input: &str) -> Result<Vec<TerminalLine>, InputError> {
+        parse_input(input, "the terminal transcript", Self::parse_transcript)
+    }
+
+    fn parse_transcript(input: &str) -> ParseResult<'_, Vec<TerminalLine>> {
+        lines(Self::parse_line)(input)
+    }
+
+    fn parse_line(input: &str) -> ParseResult<'_, TerminalLine> {
+        let command = preceded(tag("$ "), context("'cd' or 'ls'", alt((Self::parse_cd, value(TerminalLine::List, tag("ls"))))));
+        let directory = map(preceded(tag("dir "), Self::parse_name), TerminalLine::Directory);
+        let file = map(separated_pair(u64, char(' '), Self::parse_name), |(size, name)| { TerminalLine::File { size, name } });
+
+        context("a command starting with '$', 'dir' or a file size", alt((command, directory, file)))(input)
+    }
+
+    fn parse_cd(input: &str) -> ParseResult<'_, TerminalLine> {
+        let target = alt((
+            value(Target::Root, tag("/")),
+            value(Target::Parent, tag("..")),
+            map(Self::parse_name, Target::Child)
+        ));
+
+        map(preceded(tag("cd "), target), TerminalLine::ChangeDirectory)(input)
+    }
+
+    fn parse_name(input: &str) -> ParseResult<'_, String> {
+        map(context("a name", take_till1(|c| { c == '\r' || c == '\n' })), String::from)(input)
+    }
+}
+
+#[derive(Debug, Error)]
+pub(super) enum Day7Error {
+    #[error(transparent)]
+    ParseInputError {
+        #[from]
+        source: InputError
+    },
+    #[error("Line {line} changes into directory {name}, which no earlier listing of {path} showed")]
+    UnknownDirectoryError {
+        line: usize,
+        name: String,
+        path: String
+    },
+    #[error("Line {line} changes to the parent of the root directory")]
+    AboveRootError {
+        line: usize
+    },
+    #[error("Line {line} is a listing entry, but it does not follow an 'ls'")]
+    UnexpectedOutputError {
+        line: usize
+    },
+    #[error("The disk already has {free} bytes free, enough for the update without deleting anything")]
+    EnoughSpaceError {
+        free: u64
+    }
+}
@@ -0,0 +1,9 @@
+//! Solutions to Advent of Code 2022.
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
@@ -0,0 +1,162 @@
+use std::{fs, io, path::{Path, PathBuf}};
+
+use log::{debug, info};
+use thiserror::Error;
+
+use crate::days::day_input_path;
+
+/// Where puzzle inputs are downloaded from unless `--base-url` says otherwise.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent with every request so the Advent of Code maintainers can tell where automated traffic comes from.
+const USER_AGENT: &str = concat!("aoc/", env!("CARGO_PKG_VERSION"), " (puzzle input fetcher)");
+
+/// What fetching a day's input did.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum FetchOutcome {
+    /// The input was downloaded and written to this file.
+    Downloaded(PathBuf),
+    /// Nothing was requested because the input was already in this file.
+    Cached(PathBuf)
+}
+
+/// The Advent of Code site as seen by one user. Fetching and submitting both go through it.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent
+}
+
+impl Client {
+    /// A blank `session` counts as none, so the error says it is missing rather than the server rejecting it.
+    pub fn new(base_url: &str, session: Option<&str>) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.map(str::trim).filter(|session| { !session.is_empty() }).map(str::to_string),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build()
+        }
+    }
+
+    /// The URL of `page` of a day's puzzle, e.g. `input` or `answer`.
+    pub fn day_url(&self, year: u16, day: u8, page: &str) -> String {
+        format!("{}/{}/day/{}/{}", self.base_url, year, day, page)
+    }
+
+    /// A `method` request to `url` carrying the session cookie.
+    pub fn request(&self, method: &str, url: &str) -> Result<ureq::Request, MissingSessionError> {
+        let session = self.session.as_ref().ok_or(MissingSessionError)?;
+        return Ok(self.agent.request(method, url).set("Cookie", &format!("session={}", session)))
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("No session token. Set AOC_SESSION, pass --session or add `session = \"...\"` to the config file")]
+pub struct MissingSessionError;
+
+/// Downloads puzzle inputs with a logged-in session. The session is only needed once something has to be downloaded.
+pub struct Fetcher {
+    client: Client
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: Option<&str>) -> Self {
+        Fetcher { client: Client::new(base_url, session) }
+    }
+
+    pub fn input_url(&self, year: u16, day: u8) -> String {
+        self.client.day_url(year, day, "input")
+    }
+
+    /// Downloads the input of one day without touching the cache.
+    pub fn download(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        if !(1..=25).contains(&day) {
+            return Err(FetchError::InvalidDayError { day })
+        }
+
+        let url = self.input_url(year, day);
+        let request = self.client.request("GET", &url)?;
+        info!("Downloading {}", url);
+
+        let response = request.call()
+            .map_err(|e| {
+                match e {
+                    ureq::Error::Status(status, response) => FetchError::StatusError {
+                        url: url.clone(),
+                        status,
+                        message: response.into_string().unwrap_or_default().lines().next().unwrap_or_default().to_string()
+                    },
+                    ureq::Error::Transport(transport) => FetchError::RequestError {
+                        url: url.clone(),
+                        source: Box::new(transport)
+                    }
+                }
+            })?;
+
+        return response.into_string().map_err(|e| {
+            FetchError::ReadError {
+                url,
+                source: e
+            }
+        })
+    }
+
+    /// Makes sure the input of `day` is in `input_dir`, downloading it only when it is missing.
+    /// Per-part override files are made by hand and never downloaded.
+    pub fn fetch(&self, year: u16, day: u8, input_dir: &Path) -> Result<FetchOutcome, FetchError> {
+        let path = day_input_path(input_dir, year, day);
+        if path.exists() {
+            debug!("Day {} input is already cached", day);
+            return Ok(FetchOutcome::Cached(path))
+        }
+
+        let input = self.download(year, day)?;
+
+        let dir = path.parent().unwrap_or(input_dir);
+        fs::create_dir_all(dir).map_err(|e| {
+            FetchError::WriteError {
+                file_path: dir.to_path_buf(),
+                source: e
+            }
+        })?;
+        fs::write(&path, &input).map_err(|e| {
+            FetchError::WriteError {
+                file_path: path.clone(),
+                source: e
+            }
+        })?;
+
+        return Ok(FetchOutcome::Downloaded(path))
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum FetchError {
+    #[error(transparent)]
+    MissingSessionError {
+        #[from]
+        source: MissingSessionError
+    },
+    #[error("Day {day} is not an Advent of Code day")]
+    InvalidDayError { day: u8 },
+    #[error("Request to {url} failed")]
+    RequestError {
+        url: String,
+        source: Box<ureq::Transport>
+    },
+    #[error("{url} answered {status}: {message}")]
+    StatusError {
+        url: String,
+        status: u16,
+        message: String
+    },
+    #[error("Failed to read the response from {url}")]
+    ReadError {
+        url: String,
+        source: io::Error
+    },
+    #[error("Failed to write input to {}", file_path.display())]
+    WriteError {
+        file_path: PathBuf,
+        source: io::Error
+    }
+}
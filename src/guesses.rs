@@ -0,0 +1,221 @@
+use std::{collections::BTreeMap, fmt, fs, io, path::{Path, PathBuf}};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::days::{Answer, Part};
+
+/// Name of the guesses file kept next to the answers file, e.g. `input/guesses.toml`.
+pub const GUESSES_FILE_NAME: &str = "guesses.toml";
+
+/// What the server said about a submitted answer.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuessResult {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow
+}
+
+impl fmt::Display for GuessResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GuessResult::Correct => write!(f, "correct"),
+            GuessResult::Wrong => write!(f, "wrong"),
+            GuessResult::TooHigh => write!(f, "too high"),
+            GuessResult::TooLow => write!(f, "too low")
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Guess {
+    pub answer: Answer,
+    pub result: GuessResult
+}
+
+#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+struct DayGuesses {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part1: Vec<Guess>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    part2: Vec<Guess>
+}
+
+impl DayGuesses {
+    fn part(&self, part: Part) -> &Vec<Guess> {
+        match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2
+        }
+    }
+
+    fn part_mut(&mut self, part: Part) -> &mut Vec<Guess> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2
+        }
+    }
+}
+
+/// Every answer submitted so far, stored as TOML with one `[years.year.dayN]` table of `part1` and `part2` guesses,
+/// along with when the server next accepts an answer.
+#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Guesses {
+    /// Unix time in seconds before which the server will refuse another answer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    wait_until: Option<u64>,
+    #[serde(default)]
+    years: BTreeMap<String, BTreeMap<String, DayGuesses>>
+}
+
+impl Guesses {
+    /// Loads the guesses file at `path`. A missing file is treated as no guesses yet.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, GuessesError> {
+        let file_path = path.as_ref().to_path_buf();
+
+        match fs::read_to_string(&file_path) {
+            Ok(contents) => Self::from_toml(&contents).map_err(|e| {
+                GuessesError::ParseError {
+                    file_path,
+                    source: e
+                }
+            }),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Guesses::default()),
+            Err(error) => Err(GuessesError::ReadError {
+                file_path,
+                source: error
+            })
+        }
+    }
+
+    pub fn from_toml(contents: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(contents)
+    }
+
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string(self)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), GuessesError> {
+        let file_path = path.as_ref().to_path_buf();
+        let contents = self.to_toml().map_err(|e| { GuessesError::SerializeError { source: e } })?;
+
+        fs::write(&file_path, contents).map_err(|e| {
+            GuessesError::WriteError {
+                file_path,
+                source: e
+            }
+        })
+    }
+
+    pub fn guesses(&self, year: u16, day: u8, part: Part) -> &[Guess] {
+        self.years.get(&year.to_string())
+            .and_then(|days| { days.get(&Self::day_key(day)) })
+            .map_or(&[], |guesses| { guesses.part(part).as_slice() })
+    }
+
+    /// Checks whether `answer` is worth submitting at unix time `now`, given everything guessed before.
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &Answer, now: u64) -> Result<(), GuessRefusal> {
+        if let Answer::Grid(_) = answer {
+            return Err(GuessRefusal::GridAnswer {})
+        }
+
+        let guesses = self.guesses(year, day, part);
+        if let Some(solved) = guesses.iter().find(|guess| { guess.result == GuessResult::Correct }) {
+            return Err(GuessRefusal::AlreadySolved { day, part, answer: solved.answer.clone() })
+        }
+
+        if let Some(guess) = guesses.iter().find(|guess| { guess.answer.matches(answer) }) {
+            return Err(GuessRefusal::DuplicateGuess { answer: answer.clone(), result: guess.result })
+        }
+
+        if let Answer::Integer(value) = answer {
+            let bound = |result: GuessResult| {
+                guesses.iter()
+                    .filter(move |guess| { guess.result == result })
+                    .filter_map(|guess| { if let Answer::Integer(bound) = guess.answer { Some(bound) } else { None } })
+            };
+
+            if let Some(high) = bound(GuessResult::TooHigh).filter(|high| { value >= high }).min() {
+                return Err(GuessRefusal::TooHigh { answer: *value, bound: high })
+            }
+            if let Some(low) = bound(GuessResult::TooLow).filter(|low| { value <= low }).max() {
+                return Err(GuessRefusal::TooLow { answer: *value, bound: low })
+            }
+        }
+
+        return match self.wait_until {
+            Some(wait_until) if wait_until > now => Err(GuessRefusal::RateLimited { seconds: wait_until - now }),
+            _ => Ok(())
+        }
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: Part, answer: Answer, result: GuessResult) {
+        let guesses = self.years.entry(year.to_string()).or_default().entry(Self::day_key(day)).or_default();
+        guesses.part_mut(part).push(Guess { answer, result });
+    }
+
+    /// Remembers that the server will not take another answer before unix time `wait_until`.
+    pub fn wait_until(&mut self, wait_until: u64) {
+        self.wait_until = Some(self.wait_until.map_or(wait_until, |current| { current.max(wait_until) }));
+    }
+
+    fn day_key(day: u8) -> String {
+        format!("day{}", day)
+    }
+}
+
+/// Why an answer is not submitted.
+#[derive(Error, Clone, PartialEq, Eq, Debug)]
+pub enum GuessRefusal {
+    #[error("Multi-line answers, such as pictures or one value per input line, have to be read and submitted as text")]
+    GridAnswer {},
+    #[error("Day {day}{} is already solved with {answer}", part.suffix())]
+    AlreadySolved {
+        day: u8,
+        part: Part,
+        answer: Answer
+    },
+    #[error("{answer} was already submitted and was {result}")]
+    DuplicateGuess {
+        answer: Answer,
+        result: GuessResult
+    },
+    #[error("{answer} is too high, {bound} already was")]
+    TooHigh {
+        answer: i64,
+        bound: i64
+    },
+    #[error("{answer} is too low, {bound} already was")]
+    TooLow {
+        answer: i64,
+        bound: i64
+    },
+    #[error("The server takes no answers for another {seconds}s")]
+    RateLimited { seconds: u64 }
+}
+
+#[derive(Error, Debug)]
+pub enum GuessesError {
+    #[error("Failed to read guesses from {}", file_path.display())]
+    ReadError {
+        file_path: PathBuf,
+        source: io::Error
+    },
+    #[error("Failed to parse guesses in {}", file_path.display())]
+    ParseError {
+        file_path: PathBuf,
+        source: toml::de::Error
+    },
+    #[error("Failed to serialize guesses")]
+    SerializeError {
+        source: toml::ser::Error
+    },
+    #[error("Failed to write guesses to {}", file_path.display())]
+    WriteError {
+        file_path: PathBuf,
+        source: io::Error
+    }
+}
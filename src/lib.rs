@@ -1,2 +1,15 @@
+// Explicit `return`s, `XxxError` variant names and the `RPS` acronym are house style.
+#![allow(clippy::needless_return, clippy::enum_variant_names, clippy::upper_case_acronyms)]
+
 pub mod parser;
-pub mod days;
\ No newline at end of file
+pub mod days;
+pub mod runner;
+pub mod answers;
+pub mod report;
+pub mod config;
+pub mod fetch;
+pub mod guesses;
+pub mod submit;
+pub mod scaffold;
+pub mod migrate;
+pub mod check;
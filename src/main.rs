@@ -1,10 +1,320 @@
-use aoc_22::{parser::args::Args, days::solve_day};
+use std::{path::Path, time::{SystemTime, UNIX_EPOCH}};
+
+use aoc::{parser::args::{Args, CheckArgs, Command, FetchArgs, MigrateArgs, NewArgs, SubmitArgs}, answers::{Answers, Verdict}, days::{solve_day, Answer, DayError, Part, registry::{Registry, Solver, SolverKey}}, runner::{Runner, RunStatus, error_chain}, report::{self, OutputFormat}, fetch::{Fetcher, FetchOutcome}, guesses::{Guesses, GuessResult}, submit::{Submitter, SubmitOutcome}, days::InputSource, scaffold::scaffold_day, migrate::{apply_migration, plan_migration}, check::check_day};
 use clap::Parser;
+use log::{error, info};
 
 fn main() {
     let args = Args::parse();
+    env_logger::Builder::new()
+        .filter_level(args.log_level())
+        .parse_env("RUST_LOG")
+        .format_timestamp(None)
+        .init();
+
+    if let Some(command) = &args.command {
+        match command {
+            Command::Fetch(fetch_args) => fetch_inputs(fetch_args, args.year(), &args.input_dir),
+            Command::Submit(submit_args) => submit_answer(submit_args, args.year(), &args.input_dir),
+            Command::New(new_args) => new_day(new_args, args.year()),
+            Command::Migrate(migrate_args) => migrate_inputs(migrate_args, args.year(), &args.input_dir),
+            Command::Check(check_args) => check_inputs(check_args, args.year(), &args.input_dir)
+        }
+        return
+    }
+
+    if args.list {
+        for solver in Registry::all().into_iter().filter(|solver| { args.year.is_none_or(|year| { solver.key.year == year }) }) {
+            println!("{} Day {:>2} Part {}  {:<8} {}", solver.key.year, solver.key.day, solver.key.part, solver.name(), solver.input_path(&args.input_dir).display());
+        }
+        return
+    }
+
+    let source = match args.input_source() {
+        Ok(source) => source,
+        Err(error) => {
+            error!("{}", error_chain(&error));
+            std::process::exit(1)
+        }
+    };
+
+    let answers_path = args.answers_path();
+    if args.record && answers_path.is_none() {
+        error!("--record needs --answers when the input comes from --input");
+        std::process::exit(2)
+    }
+
+    let loaded_answers = answers_path.as_ref().map_or(Ok(Answers::default()), Answers::load);
+    let mut answers = match loaded_answers {
+        Ok(answers) => answers,
+        Err(error) => {
+            error!("{}", error_chain(&error));
+            std::process::exit(1)
+        }
+    };
+
+    let solvers = if args.all {
+        Registry::all().into_iter()
+            .filter(|solver| { args.year.is_none_or(|year| { solver.key.year == year }) })
+            .collect::<Vec<Solver>>()
+    } else if let Some(range) = &args.days {
+        Registry::all().into_iter()
+            .filter(|solver| { solver.key.year == args.year() && range.contains(solver.key.day) })
+            .collect::<Vec<Solver>>()
+    } else {
+        let year = args.year();
+        let day = args.day.expect("clap requires --day unless another mode is selected");
+
+        if args.bench.is_none() && args.format == OutputFormat::Text {
+            let part = Part::from_advanced(args.advanced);
+
+            info!("Running {} Day {}{}...", year, day, part.suffix());
+            let answer = match solve_day(year, day, args.advanced, &source) {
+                Ok(answer) => answer,
+                Err(error) => {
+                    error!("{}", error_chain(&error));
+                    std::process::exit(1)
+                }
+            };
+            println!("{}", answer);
+
+            let verdict = answers.verify(year, day, part, &answer);
+            match &verdict {
+                Verdict::Fail { expected } if expected.is_multiline() => error!("Check: {} (expected)\n{}", verdict.label(), expected),
+                Verdict::Fail { expected } => error!("Check: {} (expected {})", verdict.label(), expected),
+                _ => info!("Check: {}", verdict.label())
+            }
+
+            if let (true, Some(path)) = (args.record, &answers_path) {
+                record_answers(&mut answers, path, vec![(SolverKey { year, day, part }, answer)]);
+            } else if matches!(verdict, Verdict::Fail { .. }) {
+                std::process::exit(1)
+            }
+            return
+        }
+
+        match Registry::get(year, day, Part::from_advanced(args.advanced)) {
+            Some(solver) => vec![solver],
+            None => {
+                error!("{}", DayError::InvalidDay { year, day });
+                std::process::exit(1)
+            }
+        }
+    };
+
+    // Days picked by --all or --days may not have been fetched yet, but a day asked for with --day needs its input.
+    let skip_missing = args.all || args.days.is_some();
+    let keep_or_skip = |status: RunStatus| { if skip_missing { status.skip_missing_input(&source) } else { status } };
+
+    let (succeeded, skipped) = if let Some(iterations) = args.bench {
+        let outcomes = solvers.iter().map(|solver| {
+            let mut outcome = Runner::bench(solver, &source, iterations as usize);
+            outcome.status = keep_or_skip(outcome.status);
+            outcome
+        }).collect::<Vec<_>>();
+        print_report(report::render_bench(args.format, &outcomes));
+        let skipped = outcomes.iter().filter(|outcome| { matches!(outcome.status, RunStatus::Skipped(_)) }).count();
+        (!outcomes.iter().any(|outcome| { outcome.status.is_failure() }), skipped)
+    } else {
+        let outcomes = Runner::run_all(&solvers, &source, args.jobs as usize).into_iter().map(|mut outcome| {
+            outcome.status = keep_or_skip(outcome.status);
+            outcome
+        }).collect::<Vec<_>>();
+        print_report(report::render_runs(args.format, &outcomes, &answers));
+
+        if let (true, Some(path)) = (args.record, &answers_path) {
+            let solved = outcomes.iter().filter_map(|outcome| {
+                match &outcome.status {
+                    RunStatus::Solved(answer) => Some((outcome.key, answer.clone())),
+                    _ => None
+                }
+            }).collect();
+            record_answers(&mut answers, path, solved);
+        }
+
+        let skipped = outcomes.iter().filter(|outcome| { matches!(outcome.status, RunStatus::Skipped(_)) }).count();
+        let succeeded = outcomes.iter().all(|outcome| {
+            !outcome.status.is_failure() && (args.record || !matches!(outcome.verdict(&answers), Some(Verdict::Fail { .. })))
+        });
+        (succeeded, skipped)
+    };
+
+    if skipped > 0 && skipped == solvers.len() {
+        error!("All {} selected parts were skipped because their input files are missing, fetch them with `aoc fetch`", skipped);
+        std::process::exit(1)
+    }
+    if !succeeded {
+        std::process::exit(1)
+    }
+}
+
+fn fetch_inputs(fetch_args: &FetchArgs, year: u16, input_dir: &Path) {
+    let session = match fetch_args.account.session() {
+        Ok(session) => session,
+        Err(error) => {
+            error!("{}", error_chain(&error));
+            std::process::exit(1)
+        }
+    };
+    let fetcher = Fetcher::new(&fetch_args.account.base_url, session.as_deref());
+
+    let mut succeeded = true;
+    for day in fetch_args.days() {
+        match fetcher.fetch(year, day, input_dir) {
+            Ok(FetchOutcome::Downloaded(path)) => println!("Day {}: downloaded to {}", day, path.display()),
+            Ok(FetchOutcome::Cached(path)) => println!("Day {}: cached in {}", day, path.display()),
+            Err(error) => {
+                error!("Day {}: {}", day, error_chain(&error));
+                succeeded = false
+            }
+        }
+    }
+
+    if !succeeded {
+        std::process::exit(1)
+    }
+}
+
+fn submit_answer(submit_args: &SubmitArgs, year: u16, input_dir: &Path) {
+    let day = submit_args.day;
+    let part = Part::from_advanced(submit_args.advanced);
+    let exit_with = |error: &dyn std::error::Error| -> ! {
+        error!("{}", error_chain(error));
+        std::process::exit(1)
+    };
+
+    let answer = match &submit_args.answer {
+        Some(answer) => {
+            let Ok(answer) = answer.parse::<Answer>();
+            answer
+        },
+        None => solve_day(year, day, submit_args.advanced, &InputSource::Directory(input_dir.to_path_buf())).unwrap_or_else(|error| { exit_with(&error) })
+    };
+
+    let answers_path = submit_args.answers_path(input_dir);
+    let mut answers = Answers::load(&answers_path).unwrap_or_else(|error| { exit_with(&error) });
+    if let Some(expected) = answers.expected(year, day, part) {
+        error!("Day {}{} is already solved with {} according to {}", day, part.suffix(), expected, answers_path.display());
+        std::process::exit(1)
+    }
+
+    let guesses_path = submit_args.guesses_path(input_dir);
+    let mut guesses = Guesses::load(&guesses_path).unwrap_or_else(|error| { exit_with(&error) });
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |since_epoch| { since_epoch.as_secs() });
+    if let Err(refusal) = guesses.check(year, day, part, &answer, now) {
+        error!("Not submitting {}: {}", answer, refusal);
+        std::process::exit(1)
+    }
+
+    let session = submit_args.account.session().unwrap_or_else(|error| { exit_with(&error) });
+    let submitter = Submitter::new(&submit_args.account.base_url, session.as_deref());
+    let outcome = submitter.submit(year, day, part, &answer).unwrap_or_else(|error| { exit_with(&error) });
+
+    let correct = match outcome {
+        SubmitOutcome::Correct => {
+            println!("Day {}{}: {} is correct", day, part.suffix(), answer);
+            guesses.record(year, day, part, answer.clone(), GuessResult::Correct);
+            answers.record(year, day, part, answer);
+            answers.save(&answers_path).unwrap_or_else(|error| { exit_with(&error) });
+            true
+        },
+        SubmitOutcome::Wrong { result, wait } => {
+            println!("Day {}{}: {} is {}", day, part.suffix(), answer, result);
+            guesses.record(year, day, part, answer, result);
+            if let Some(wait) = wait {
+                guesses.wait_until(now + wait.as_secs());
+            }
+            false
+        },
+        SubmitOutcome::TooSoon { wait } => {
+            println!("Day {}{}: answered too recently, wait {}s", day, part.suffix(), wait.as_secs());
+            guesses.wait_until(now + wait.as_secs());
+            false
+        },
+        SubmitOutcome::WrongLevel => {
+            println!("Day {}{}: not open for answers, it is either solved already or still locked", day, part.suffix());
+            false
+        }
+    };
+
+    guesses.save(&guesses_path).unwrap_or_else(|error| { exit_with(&error) });
+    if !correct {
+        std::process::exit(1)
+    }
+}
+
+fn new_day(new_args: &NewArgs, year: u16) {
+    match scaffold_day(&new_args.root, year, new_args.day) {
+        Ok(paths) => {
+            println!("Created {}", paths.module.display());
+            println!("Updated {}", paths.year_mod.display());
+            println!("Updated {}", paths.days_mod.display());
+            println!("Updated {}", paths.example_tests.display());
+        },
+        Err(error) => {
+            error!("{}", error_chain(&error));
+            std::process::exit(1)
+        }
+    }
+}
+
+fn migrate_inputs(migrate_args: &MigrateArgs, year: u16, input_dir: &Path) {
+    let migrated = plan_migration(input_dir, year).and_then(|steps| {
+        for step in steps.iter() {
+            println!("{}", step);
+        }
+        if steps.is_empty() {
+            info!("{} already has one input per day", input_dir.display());
+        }
+
+        if migrate_args.dry_run { Ok(()) } else { apply_migration(&steps) }
+    });
+
+    if let Err(error) = migrated {
+        error!("{}", error_chain(&error));
+        std::process::exit(1)
+    }
+}
+
+fn check_inputs(check_args: &CheckArgs, year: u16, input_dir: &Path) {
+    let checks = check_args.input_source(input_dir)
+        .map_err(|error| { error_chain(&error) })
+        .and_then(|source| { check_day(year, check_args.day, &source).map_err(|error| { error_chain(&error) }) });
+
+    match checks {
+        Ok(checks) => {
+            for check in checks.iter() {
+                println!("{}", check);
+            }
+            if !checks.iter().all(|check| { check.is_ok() }) {
+                std::process::exit(1)
+            }
+        },
+        Err(message) => {
+            error!("{}", message);
+            std::process::exit(1)
+        }
+    }
+}
+
+fn print_report(rendered: Result<String, report::ReportError>) {
+    match rendered {
+        Ok(report) => println!("{}", report),
+        Err(error) => {
+            error!("{}", error_chain(&error));
+            std::process::exit(1)
+        }
+    }
+}
+
+fn record_answers(answers: &mut Answers, path: &Path, solved: Vec<(SolverKey, Answer)>) {
+    for (key, answer) in solved {
+        info!("Recording {} = {}", key, answer);
+        answers.record(key.year, key.day, key.part, answer);
+    }
 
-    println!("Running Day {}{}...", args.day, if args.advanced {"a"} else {""});
-    let result = solve_day(args.day, args.advanced);
-    println!("Result: {:#?}", result)
+    if let Err(error) = answers.save(path) {
+        error!("{}", error_chain(&error));
+        std::process::exit(1)
+    }
 }
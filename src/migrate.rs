@@ -0,0 +1,185 @@
+use std::{collections::BTreeMap, fmt, fs, io, path::{Path, PathBuf}};
+
+use thiserror::Error;
+
+use crate::days::{day_input_path, part_input_path, Part};
+
+/// One change that brings an input directory to the `{year}/{day}.txt` layout.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum MigrationStep {
+    Move {
+        from: PathBuf,
+        to: PathBuf
+    },
+    /// `path` holds the same input as `duplicate_of`, so it is deleted.
+    Remove {
+        path: PathBuf,
+        duplicate_of: PathBuf
+    }
+}
+
+impl fmt::Display for MigrationStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrationStep::Move { from, to } => write!(f, "move {} to {}", from.display(), to.display()),
+            MigrationStep::Remove { path, duplicate_of } => write!(f, "remove {}, a copy of {}", path.display(), duplicate_of.display())
+        }
+    }
+}
+
+/// Works out how to move the inputs in `input_dir` from the old `{day}.txt`/`{day}a.txt` layout to one file per day.
+/// Files directly in `input_dir` predate per-year directories and are moved into the one for `year`.
+/// An advanced input that differs from the day's input becomes that day's `{day}.part2.txt` override.
+pub fn plan_migration(input_dir: &Path, year: u16) -> Result<Vec<MigrationStep>, MigrateError> {
+    let mut steps = plan_directory(input_dir, year, input_dir)?;
+
+    let mut year_dirs = read_dir(input_dir)?.into_iter()
+        .filter(|path| { path.is_dir() })
+        .filter_map(|path| { path.file_name()?.to_str()?.parse::<u16>().ok().map(|dir_year| { (dir_year, path) }) })
+        .collect::<Vec<(u16, PathBuf)>>();
+    year_dirs.sort();
+    for (dir_year, year_dir) in year_dirs {
+        steps.extend(plan_directory(input_dir, dir_year, &year_dir)?);
+    }
+
+    return Ok(steps)
+}
+
+/// Carries out `steps` in order.
+pub fn apply_migration(steps: &[MigrationStep]) -> Result<(), MigrateError> {
+    for step in steps {
+        match step {
+            MigrationStep::Move { from, to } => {
+                if let Some(dir) = to.parent() {
+                    fs::create_dir_all(dir).map_err(|e| {
+                        MigrateError::MoveError {
+                            from: from.clone(),
+                            to: to.clone(),
+                            source: e
+                        }
+                    })?;
+                }
+                fs::rename(from, to).map_err(|e| {
+                    MigrateError::MoveError {
+                        from: from.clone(),
+                        to: to.clone(),
+                        source: e
+                    }
+                })?;
+            },
+            MigrationStep::Remove { path, .. } => {
+                fs::remove_file(path).map_err(|e| {
+                    MigrateError::RemoveError {
+                        file_path: path.clone(),
+                        source: e
+                    }
+                })?;
+            }
+        }
+    }
+
+    return Ok(())
+}
+
+/// Plans the old-style inputs found in `dir`, which all belong to `year`.
+fn plan_directory(input_dir: &Path, year: u16, dir: &Path) -> Result<Vec<MigrationStep>, MigrateError> {
+    let mut days = BTreeMap::<u8, [Option<PathBuf>; 2]>::new();
+    for path in read_dir(dir)?.into_iter().filter(|path| { path.is_file() }) {
+        if let Some((day, part)) = path.file_name().and_then(|name| { name.to_str() }).and_then(legacy_input) {
+            days.entry(day).or_default()[usize::from(part.is_advanced())] = Some(path);
+        }
+    }
+
+    let mut steps = Vec::new();
+    for (day, [first, second]) in days {
+        let day_path = day_input_path(input_dir, year, day);
+        let mut day_input = if day_path.exists() { Some(read(&day_path)?) } else { None };
+
+        if let Some(first) = first.filter(|first| { *first != day_path }) {
+            let input = read(&first)?;
+            match &day_input {
+                None => steps.push(MigrationStep::Move { from: first, to: day_path.clone() }),
+                Some(existing) if *existing == input => steps.push(MigrationStep::Remove { path: first, duplicate_of: day_path.clone() }),
+                Some(_) => return Err(MigrateError::ConflictError { from: first, to: day_path })
+            }
+            day_input = Some(input);
+        }
+
+        if let Some(second) = second {
+            let input = read(&second)?;
+            let override_path = part_input_path(input_dir, year, day, Part::Two);
+            let step = match &day_input {
+                None => MigrationStep::Move { from: second, to: day_path },
+                Some(existing) if *existing == input => MigrationStep::Remove { path: second, duplicate_of: day_path },
+                Some(_) if !override_path.exists() => MigrationStep::Move { from: second, to: override_path },
+                Some(_) if read(&override_path)? == input => MigrationStep::Remove { path: second, duplicate_of: override_path },
+                Some(_) => return Err(MigrateError::ConflictError { from: second, to: override_path })
+            };
+            steps.push(step);
+        }
+    }
+
+    return Ok(steps)
+}
+
+/// Reads an old-style input file name, `1.txt` for part one or `1a.txt` for part two.
+fn legacy_input(name: &str) -> Option<(u8, Part)> {
+    let stem = name.strip_suffix(".txt")?;
+    let (day, part) = match stem.strip_suffix('a') {
+        Some(day) => (day, Part::Two),
+        None => (stem, Part::One)
+    };
+
+    let day = day.parse::<u8>().ok().filter(|day| { (1..=25).contains(day) })?;
+    return Some((day, part))
+}
+
+fn read_dir(dir: &Path) -> Result<Vec<PathBuf>, MigrateError> {
+    let entries = fs::read_dir(dir).map_err(|e| {
+        MigrateError::ReadError {
+            file_path: dir.to_path_buf(),
+            source: e
+        }
+    })?;
+
+    return entries.map(|entry| { entry.map(|entry| { entry.path() }) }).collect::<Result<Vec<PathBuf>, io::Error>>().map_err(|e| {
+        MigrateError::ReadError {
+            file_path: dir.to_path_buf(),
+            source: e
+        }
+    })
+}
+
+fn read(file_path: &Path) -> Result<String, MigrateError> {
+    fs::read_to_string(file_path).map_err(|e| {
+        MigrateError::ReadError {
+            file_path: file_path.to_path_buf(),
+            source: e
+        }
+    })
+}
+
+#[derive(Error, Debug)]
+pub enum MigrateError {
+    #[error("Failed to read {}", file_path.display())]
+    ReadError {
+        file_path: PathBuf,
+        source: io::Error
+    },
+    #[error("{} and {} hold different inputs for the same day. Remove the wrong one and migrate again", from.display(), to.display())]
+    ConflictError {
+        from: PathBuf,
+        to: PathBuf
+    },
+    #[error("Failed to move {} to {}", from.display(), to.display())]
+    MoveError {
+        from: PathBuf,
+        to: PathBuf,
+        source: io::Error
+    },
+    #[error("Failed to remove {}", file_path.display())]
+    RemoveError {
+        file_path: PathBuf,
+        source: io::Error
+    }
+}
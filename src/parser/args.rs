@@ -1,14 +1,287 @@
-use clap::Parser;
+use std::{ops::RangeInclusive, path::{Path, PathBuf}, str::FromStr};
+
+use clap::{ArgAction, Parser, Subcommand};
+use log::LevelFilter;
+
+use crate::{answers::ANSWERS_FILE_NAME, config::{Config, ConfigError, CONFIG_FILE_NAME}, guesses::GUESSES_FILE_NAME, days::{InputSource, DEFAULT_INPUT_DIR, DEFAULT_YEAR}, fetch::DEFAULT_BASE_URL, report::OutputFormat};
+
+use super::{read_stdin, InputError};
 
 /// Struct defining the arguments this program will accept from the command line
 #[derive(Debug, Parser)]
-#[command(author, version, about, long_about = None)]
+#[command(author, version, about, long_about = None, subcommand_negates_reqs = true)]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// The Advent of Code event to work on. Defaults to 2022, except that `--list` and `--all` cover every event.
+    #[arg(short, long, env = "AOC_YEAR", global = true)]
+    pub year: Option<u16>,
+
     /// The day of Advent of Code that we will be running the program for.
+    #[arg(short, long, required_unless_present_any = ["list", "all", "days"])]
+    pub day: Option<u8>,
+
+    /// Each day has two parts: regular and advanced. Passing this flag means you want to run the advanced portion.
     #[arg(short, long)]
+    pub advanced: bool,
+
+    /// List every registered solver instead of running one.
+    #[arg(short, long, conflicts_with_all = ["day", "advanced", "all", "days"])]
+    pub list: bool,
+
+    /// Run both parts of every registered day and print a summary table.
+    #[arg(long, conflicts_with_all = ["day", "advanced", "days"])]
+    pub all: bool,
+
+    /// Run both parts of every registered day in a range such as `3`, `1..5` or `1..=5` and print a summary table.
+    #[arg(long, value_name = "RANGE", conflicts_with_all = ["day", "advanced"])]
+    pub days: Option<DayRange>,
+
+    /// Run each selected solver N times and report min/median/max of the read, parse and solve phases.
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..), conflicts_with = "list")]
+    pub bench: Option<u32>,
+
+    /// Solve up to N days at once on a pool of worker threads. Results are still printed in day order.
+    #[arg(short, long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..), conflicts_with_all = ["list", "bench"])]
+    pub jobs: u32,
+
+    /// Read the puzzle input from this file instead of the day's default. Pass `-` to read it from stdin.
+    #[arg(short, long, value_name = "PATH", conflicts_with_all = ["list", "all", "days"])]
+    pub input: Option<PathBuf>,
+
+    /// Directory holding a `{year}` directory of `{day}.txt` input files for each event.
+    #[arg(long, value_name = "DIR", env = "AOC_INPUT_DIR", default_value = DEFAULT_INPUT_DIR, global = true)]
+    pub input_dir: PathBuf,
+
+    /// TOML file of known-correct answers that results are checked against. Defaults to `answers.toml` in the input directory.
+    #[arg(long, value_name = "PATH")]
+    pub answers: Option<PathBuf>,
+
+    /// Write every answer produced by this run into the answers file, replacing what was stored before.
+    #[arg(long, conflicts_with_all = ["list", "bench"])]
+    pub record: bool,
+
+    /// How results are printed. `json` and `csv` follow a stable schema meant for scripts.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, conflicts_with = "list")]
+    pub format: OutputFormat,
+
+    /// Print more diagnostics on stderr: `-v` progress, `-vv` debug output from solvers, `-vvv` everything.
+    /// `RUST_LOG` can narrow this down to one day, e.g. `RUST_LOG=aoc::days::y2022::day1=trace`.
+    #[arg(short, long, action = ArgAction::Count, conflicts_with = "quiet", global = true)]
+    pub verbose: u8,
+
+    /// Print fewer diagnostics on stderr: `-q` only errors, `-qq` nothing at all.
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    pub quiet: u8
+}
+
+impl Args {
+    /// The event picked by `--year`, or the default one.
+    pub fn year(&self) -> u16 {
+        self.year.unwrap_or(DEFAULT_YEAR)
+    }
+
+    /// Resolves `--input` and `--input-dir` into an `InputSource`, reading stdin up front when it was asked for.
+    pub fn input_source(&self) -> Result<InputSource, InputError> {
+        input_source(self.input.as_deref(), &self.input_dir)
+    }
+
+    /// Log level picked by `-v`/`-q`. Warnings and errors are shown when neither is given.
+    pub fn log_level(&self) -> LevelFilter {
+        match (self.verbose, self.quiet) {
+            (0, 0) => LevelFilter::Warn,
+            (0, 1) => LevelFilter::Error,
+            (0, _) => LevelFilter::Off,
+            (1, _) => LevelFilter::Info,
+            (2, _) => LevelFilter::Debug,
+            (_, _) => LevelFilter::Trace
+        }
+    }
+
+    /// The answers file to check against. Answers only belong to the input directory they sit in,
+    /// so there is none by default when `--input` points somewhere else.
+    pub fn answers_path(&self) -> Option<PathBuf> {
+        match (&self.answers, &self.input) {
+            (Some(path), _) => Some(path.clone()),
+            (None, Some(_)) => None,
+            (None, None) => Some(self.input_dir.join(ANSWERS_FILE_NAME))
+        }
+    }
+}
+
+/// Tasks other than solving puzzles.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Download puzzle inputs into the input directory. Inputs that are already there are never downloaded again.
+    Fetch(FetchArgs),
+    /// Submit an answer, refusing ones that earlier guesses already rule out.
+    Submit(SubmitArgs),
+    /// Start a new day from the template: its module, its `pub mod` line and its example test.
+    New(NewArgs),
+    /// Move an input directory from `{day}.txt`/`{day}a.txt` files to one `{year}/{day}.txt` per day,
+    /// removing advanced inputs that only duplicate the day's input.
+    Migrate(MigrateArgs),
+    /// Run only a day's parser over its input and report the input's shape and anything suspicious about it.
+    Check(CheckArgs)
+}
+
+/// How to reach adventofcode.com as a logged-in user.
+#[derive(Debug, clap::Args)]
+pub struct AccountArgs {
+    /// Session cookie of a logged-in adventofcode.com browser. Overrides the one in the config file.
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    pub session: Option<String>,
+
+    /// Config file that may hold `session = "..."`.
+    #[arg(long, value_name = "PATH", env = "AOC_CONFIG", default_value = CONFIG_FILE_NAME)]
+    pub config: PathBuf,
+
+    /// Server to talk to instead of adventofcode.com.
+    #[arg(long, value_name = "URL", env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    pub base_url: String
+}
+
+impl AccountArgs {
+    /// The session token from `--session`/`AOC_SESSION`, falling back to the config file.
+    pub fn session(&self) -> Result<Option<String>, ConfigError> {
+        if self.session.is_some() {
+            return Ok(self.session.clone())
+        }
+
+        return Config::load(&self.config).map(|config| { config.session })
+    }
+}
+
+#[derive(Debug, clap::Args)]
+pub struct FetchArgs {
+    /// Days to fetch, each a day or a range such as `1..=5`.
+    #[arg(required = true, value_name = "DAYS")]
+    pub days: Vec<DayRange>,
+
+    #[command(flatten)]
+    pub account: AccountArgs
+}
+
+impl FetchArgs {
+    /// Every requested day once, in ascending order.
+    pub fn days(&self) -> Vec<u8> {
+        let mut days = self.days.iter().flat_map(|range| { range.0.clone() }).collect::<Vec<u8>>();
+        days.sort();
+        days.dedup();
+        return days
+    }
+}
+
+#[derive(Debug, clap::Args)]
+pub struct SubmitArgs {
+    /// The day whose answer is submitted.
     pub day: u8,
 
-    /// Each day has two parts: regular and advanced. Passing this flag means you want to run the advanced portion.
+    /// The answer to submit. When left out, the day is solved and its answer submitted.
+    pub answer: Option<String>,
+
+    /// Submit the advanced part of the day.
     #[arg(short, long)]
-    pub advanced: bool
-}
\ No newline at end of file
+    pub advanced: bool,
+
+    /// File recording every answer submitted so far. Defaults to `guesses.toml` in the input directory.
+    #[arg(long, value_name = "PATH")]
+    pub guesses: Option<PathBuf>,
+
+    /// Known-correct answers file. A correct answer is added to it. Defaults to `answers.toml` in the input directory.
+    #[arg(long, value_name = "PATH")]
+    pub answers: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub account: AccountArgs
+}
+
+impl SubmitArgs {
+    pub fn guesses_path(&self, input_dir: &Path) -> PathBuf {
+        self.guesses.clone().unwrap_or_else(|| { input_dir.join(GUESSES_FILE_NAME) })
+    }
+
+    pub fn answers_path(&self, input_dir: &Path) -> PathBuf {
+        self.answers.clone().unwrap_or_else(|| { input_dir.join(ANSWERS_FILE_NAME) })
+    }
+}
+
+#[derive(Debug, clap::Args)]
+pub struct NewArgs {
+    /// The day to create.
+    pub day: u8,
+
+    /// Root of the crate to add the day to.
+    #[arg(long, value_name = "DIR", default_value = ".")]
+    pub root: PathBuf
+}
+
+#[derive(Debug, clap::Args)]
+pub struct MigrateArgs {
+    /// Print what would change without touching any file.
+    #[arg(long)]
+    pub dry_run: bool
+}
+
+#[derive(Debug, clap::Args)]
+pub struct CheckArgs {
+    /// The day whose input is checked.
+    pub day: u8,
+
+    /// Check this file instead of the day's input. Pass `-` to read it from stdin.
+    #[arg(short, long, value_name = "PATH")]
+    pub input: Option<PathBuf>
+}
+
+impl CheckArgs {
+    pub fn input_source(&self, input_dir: &Path) -> Result<InputSource, InputError> {
+        input_source(self.input.as_deref(), input_dir)
+    }
+}
+
+/// The `--input` file, stdin when it is `-`, or else the day's file in `input_dir`.
+fn input_source(input: Option<&Path>, input_dir: &Path) -> Result<InputSource, InputError> {
+    match input {
+        Some(path) if path.as_os_str() == "-" => read_stdin().map(InputSource::Text),
+        Some(path) => Ok(InputSource::File(path.to_path_buf())),
+        None => Ok(InputSource::Directory(input_dir.to_path_buf()))
+    }
+}
+
+/// Inclusive range of days accepted by `--days`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayRange(pub RangeInclusive<u8>);
+
+impl DayRange {
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.contains(&day)
+    }
+}
+
+impl FromStr for DayRange {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str| {
+            u8::from_str(day.trim()).map_err(|e| { format!("invalid day '{}' in range '{}': {}", day, input, e) })
+        };
+
+        let range = if let Some((start, end)) = input.split_once("..=") {
+            parse_day(start)?..=parse_day(end)?
+        } else if let Some((start, end)) = input.split_once("..") {
+            let end = parse_day(end)?;
+            let last = end.checked_sub(1).ok_or(format!("range '{}' is empty", input))?;
+            parse_day(start)?..=last
+        } else {
+            let day = parse_day(input)?;
+            day..=day
+        };
+
+        return if range.is_empty() {
+            Err(format!("range '{}' is empty", input))
+        } else {
+            Ok(DayRange(range))
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use nom::{Parser, character::complete::line_ending, error::{context, VerboseError}};
+
+use super::ParseResult;
+
+/// Parses one `line` per line of the input. Every line has to parse completely, lines may end in `\n` or `\r\n`
+/// and the last line ending is optional, so a corrupted line is an error rather than where the input silently stops.
+pub fn lines<'a, O, F>(mut line: F) -> impl FnMut(&'a str) -> ParseResult<'a, Vec<O>>
+where
+    F: Parser<&'a str, O, VerboseError<&'a str>>
+{
+    move |mut input: &'a str| {
+        let mut values = Vec::new();
+        loop {
+            let (rest, value) = line.parse(input)?;
+            values.push(value);
+            if rest.is_empty() {
+                return Ok((rest, values))
+            }
+
+            let (rest, _) = context("the end of the line", line_ending)(rest)?;
+            if rest.is_empty() {
+                return Ok((rest, values))
+            }
+            input = rest;
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use nom::{Offset, error::{ErrorKind, VerboseError, VerboseErrorKind}};
+use thiserror::Error;
+
+/// Where and why a day's parser gave up, along with the offending line so it can be shown with a caret under it:
+///
+/// ```text
+/// line 3, column 2: expected a number
+///  3 | 1x00
+///    |  ^
+/// ```
+#[derive(Error, Clone, PartialEq, Eq, Debug)]
+#[error("line {line}, column {column}: expected {expected}\n{}", self.annotated_line())]
+pub struct ParseFailure {
+    /// 1-based line of the input the parser stopped on.
+    pub line: usize,
+    /// 1-based column, in characters, of where on that line the parser stopped.
+    pub column: usize,
+    /// The whole offending line, without its line ending.
+    pub text: String,
+    pub expected: String
+}
+
+impl ParseFailure {
+    /// Locates byte `offset` of `input` and describes the failure there as having `expected` something else.
+    pub fn new(input: &str, offset: usize, expected: String) -> Self {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |index| { index + 1 });
+        let line_end = input[offset..].find('\n').map_or(input.len(), |index| { offset + index });
+
+        return ParseFailure {
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: input[line_start..line_end].trim_end_matches('\r').to_string(),
+            expected
+        }
+    }
+
+    /// Turns the error nom stopped with while parsing `input` into a failure at the innermost position it reached.
+    pub fn from_nom(input: &str, error: nom::Err<VerboseError<&str>>) -> Self {
+        let error = match error {
+            nom::Err::Error(error) | nom::Err::Failure(error) => error,
+            nom::Err::Incomplete(_) => return Self::new(input, input.len(), String::from("more input"))
+        };
+
+        let Some((remaining, kind)) = error.errors.first() else {
+            return Self::new(input, 0, String::from("valid input"))
+        };
+        let offset = input.offset(remaining);
+
+        // A `context` wrapped around the parser that failed right there says best what it was after.
+        let expected = error.errors.iter()
+            .find_map(|(context_input, kind)| {
+                match kind {
+                    VerboseErrorKind::Context(context) if input.offset(context_input) == offset => Some(context.to_string()),
+                    _ => None
+                }
+            })
+            .unwrap_or_else(|| { describe(kind) });
+
+        return Self::new(input, offset, expected)
+    }
+
+    /// The offending line behind a line-number gutter, with a caret under the column the parser stopped at.
+    pub fn annotated_line(&self) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let indent = self.text.chars().take(self.column - 1).map(|c| { if c == '\t' { '\t' } else { ' ' } }).collect::<String>();
+
+        return format!(" {} | {}\n {} | {}^", number, self.text, gutter, indent)
+    }
+}
+
+fn describe(kind: &VerboseErrorKind) -> String {
+    match kind {
+        VerboseErrorKind::Context(context) => context.to_string(),
+        VerboseErrorKind::Char(c) => format!("'{}'", c.escape_default()),
+        VerboseErrorKind::Nom(ErrorKind::Eof) => String::from("end of input"),
+        VerboseErrorKind::Nom(ErrorKind::Digit) => String::from("a number"),
+        VerboseErrorKind::Nom(ErrorKind::CrLf) => String::from("a line ending"),
+        VerboseErrorKind::Nom(kind) => format!("input matching {}", kind.description())
+    }
+}
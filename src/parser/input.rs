@@ -0,0 +1,86 @@
+use std::{fs::File, io::{self, BufReader, Read}, path::Path};
+
+use nom::{IResult, combinator::all_consuming, error::VerboseError};
+use thiserror::Error;
+
+use super::ParseFailure;
+
+/// What every day's nom parsers return. `VerboseError` keeps the `context` labels that say what was expected.
+pub type ParseResult<'a, O> = IResult<&'a str, O, VerboseError<&'a str>>;
+
+/// Reads the puzzle input at `path` into a `String`.
+pub fn read_input<P: AsRef<Path>>(path: P) -> Result<String, InputError> {
+    let location = path.as_ref().display().to_string();
+
+    let file = File::open(path).map_err(|e| {
+        InputError::OpenError {
+            location: location.clone(),
+            source: e
+        }
+    })?;
+
+    return read_from(BufReader::new(file), location)
+}
+
+/// Reads the puzzle input from stdin into a `String`.
+pub fn read_stdin() -> Result<String, InputError> {
+    read_from(io::stdin().lock(), String::from("<stdin>"))
+}
+
+fn read_from<R: Read>(mut reader: R, location: String) -> Result<String, InputError> {
+    let mut buffer = Vec::new();
+
+    let _read_result = reader.read_to_end(&mut buffer).map_err(|e| {
+        InputError::ReadError {
+            location: location.clone(),
+            source: e
+        }
+    })?;
+
+    String::from_utf8(buffer).map_err(|e| {
+        InputError::Utf8Error {
+            location,
+            source: e
+        }
+    })
+}
+
+/// Runs a day's nom `parser` over the whole input, describing what was being parsed as `what` if it fails.
+/// Input the parser leaves behind is an error too, so nothing is silently dropped.
+pub fn parse_input<'a, O, F>(input: &'a str, what: &'static str, parser: F) -> Result<O, InputError>
+where
+    F: FnMut(&'a str) -> ParseResult<'a, O>
+{
+    all_consuming(parser)(input)
+        .map(|(_, output)| { output })
+        .map_err(|e| {
+            InputError::ParseError {
+                what,
+                source: ParseFailure::from_nom(input, e)
+            }
+        })
+}
+
+#[derive(Error, Debug)]
+pub enum InputError {
+    #[error("Failed to open input {location}")]
+    OpenError {
+        location: String,
+        source: io::Error
+    },
+    #[error("Unable to read input {location}")]
+    ReadError {
+        location: String,
+        source: io::Error
+    },
+    #[error("Input {location} is not valid UTF-8")]
+    Utf8Error {
+        location: String,
+        source: std::string::FromUtf8Error
+    },
+    #[error("Failed to parse {what}")]
+    ParseError {
+        what: &'static str,
+        source: ParseFailure
+    }
+}
@@ -1 +1,8 @@
-pub mod args;
\ No newline at end of file
+pub mod args;
+pub mod combinators;
+pub mod failure;
+pub mod input;
+
+pub use combinators::lines;
+pub use failure::ParseFailure;
+pub use input::{read_input, read_stdin, parse_input, InputError, ParseResult};
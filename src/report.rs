@@ -0,0 +1,309 @@
+use std::{string::FromUtf8Error, time::Duration};
+
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::{answers::{Answers, Verdict}, days::{Answer, DayError}, runner::{BenchOutcome, DurationStats, PhaseTimings, RunOutcome, RunStatus, Runner, source_chain}};
+
+/// Bumped whenever a field is renamed or removed or can hold a value it could not before, e.g. the `skipped`
+/// status added in 2, so scripts can tell which layout they are reading.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// How results are printed by the CLI.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable tables.
+    #[default]
+    Text,
+    /// One JSON document holding every result.
+    Json,
+    /// One CSV row per result, with a header row.
+    Csv
+}
+
+/// Why a solver did not produce an answer. `chain` holds the error's message followed by each of its sources.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct ErrorRecord {
+    pub kind: &'static str,
+    pub chain: Vec<String>
+}
+
+impl ErrorRecord {
+    fn from_status(status: &RunStatus) -> Option<Self> {
+        match status {
+            RunStatus::Solved(_) => None,
+            RunStatus::Failed(error) | RunStatus::Skipped(error) => Some(ErrorRecord {
+                kind: error_kind(error),
+                chain: source_chain(error)
+            }),
+            RunStatus::Panicked(message) => Some(ErrorRecord {
+                kind: "Panic",
+                chain: vec![message.clone()]
+            })
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+pub struct TimingsRecord {
+    pub read_ns: u64,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    pub total_ns: u64
+}
+
+impl From<PhaseTimings> for TimingsRecord {
+    fn from(timings: PhaseTimings) -> Self {
+        TimingsRecord {
+            read_ns: nanos(timings.read),
+            parse_ns: nanos(timings.parse),
+            solve_ns: nanos(timings.solve),
+            total_ns: nanos(timings.total())
+        }
+    }
+}
+
+/// One solver run. `check` and `expected` come from the answers file.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct RunRecord {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub status: &'static str,
+    pub answer: Option<Answer>,
+    pub check: Option<&'static str>,
+    pub expected: Option<Answer>,
+    pub timings: TimingsRecord,
+    pub error: Option<ErrorRecord>
+}
+
+impl RunRecord {
+    pub fn new(outcome: &RunOutcome, answers: &Answers) -> Self {
+        let verdict = outcome.verdict(answers);
+        let answer = match &outcome.status {
+            RunStatus::Solved(answer) => Some(answer.clone()),
+            _ => None
+        };
+
+        return RunRecord {
+            year: outcome.key.year,
+            day: outcome.key.day,
+            part: outcome.key.part.number(),
+            status: status_name(&outcome.status),
+            answer,
+            check: verdict.map(|verdict| { verdict_name(&verdict) }),
+            expected: answers.expected(outcome.key.year, outcome.key.day, outcome.key.part).cloned(),
+            timings: TimingsRecord::from(outcome.timings),
+            error: ErrorRecord::from_status(&outcome.status)
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+pub struct StatsRecord {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64
+}
+
+impl From<DurationStats> for StatsRecord {
+    fn from(stats: DurationStats) -> Self {
+        StatsRecord {
+            min_ns: nanos(stats.min),
+            median_ns: nanos(stats.median),
+            max_ns: nanos(stats.max)
+        }
+    }
+}
+
+/// One benchmarked solver. The stats only cover the successful runs.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct BenchRecord {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub status: &'static str,
+    pub runs: usize,
+    pub read: StatsRecord,
+    pub parse: StatsRecord,
+    pub solve: StatsRecord,
+    pub total: StatsRecord,
+    pub error: Option<ErrorRecord>
+}
+
+impl BenchRecord {
+    pub fn new(outcome: &BenchOutcome) -> Self {
+        BenchRecord {
+            year: outcome.key.year,
+            day: outcome.key.day,
+            part: outcome.key.part.number(),
+            status: status_name(&outcome.status),
+            runs: outcome.runs.len(),
+            read: StatsRecord::from(outcome.read_stats()),
+            parse: StatsRecord::from(outcome.parse_stats()),
+            solve: StatsRecord::from(outcome.solve_stats()),
+            total: StatsRecord::from(outcome.total_stats()),
+            error: ErrorRecord::from_status(&outcome.status)
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct RunReport<'a> {
+    schema_version: u32,
+    results: &'a [RunRecord]
+}
+
+#[derive(Serialize)]
+struct BenchReport<'a> {
+    schema_version: u32,
+    bench: &'a [BenchRecord]
+}
+
+/// A `RunRecord` flattened into CSV columns. Nested values are joined into single cells.
+#[derive(Serialize)]
+struct RunRow {
+    year: u16,
+    day: u8,
+    part: u8,
+    status: &'static str,
+    answer: Option<String>,
+    check: Option<&'static str>,
+    expected: Option<String>,
+    read_ns: u64,
+    parse_ns: u64,
+    solve_ns: u64,
+    total_ns: u64,
+    error_kind: Option<&'static str>,
+    error: Option<String>
+}
+
+/// One phase of a `BenchRecord`, laid out like the text bench table.
+#[derive(Serialize)]
+struct BenchRow {
+    year: u16,
+    day: u8,
+    part: u8,
+    status: &'static str,
+    runs: usize,
+    phase: &'static str,
+    min_ns: u64,
+    median_ns: u64,
+    max_ns: u64,
+    error_kind: Option<&'static str>,
+    error: Option<String>
+}
+
+/// Renders run results in `format`. `Text` is the same table as `Runner::summary`.
+pub fn render_runs(format: OutputFormat, outcomes: &[RunOutcome], answers: &Answers) -> Result<String, ReportError> {
+    let records = outcomes.iter().map(|outcome| { RunRecord::new(outcome, answers) }).collect::<Vec<RunRecord>>();
+
+    match format {
+        OutputFormat::Text => Ok(Runner::summary(outcomes, answers)),
+        OutputFormat::Json => to_json(&RunReport { schema_version: SCHEMA_VERSION, results: &records }),
+        OutputFormat::Csv => to_csv(records.iter().map(|record| {
+            RunRow {
+                year: record.year,
+                day: record.day,
+                part: record.part,
+                status: record.status,
+                answer: record.answer.as_ref().map(Answer::to_string),
+                check: record.check,
+                expected: record.expected.as_ref().map(Answer::to_string),
+                read_ns: record.timings.read_ns,
+                parse_ns: record.timings.parse_ns,
+                solve_ns: record.timings.solve_ns,
+                total_ns: record.timings.total_ns,
+                error_kind: record.error.as_ref().map(|error| { error.kind }),
+                error: record.error.as_ref().map(|error| { error.chain.join(": ") })
+            }
+        }))
+    }
+}
+
+/// Renders benchmark results in `format`. `Text` is the same table as `Runner::bench_summary`.
+pub fn render_bench(format: OutputFormat, outcomes: &[BenchOutcome]) -> Result<String, ReportError> {
+    let records = outcomes.iter().map(BenchRecord::new).collect::<Vec<BenchRecord>>();
+
+    match format {
+        OutputFormat::Text => Ok(Runner::bench_summary(outcomes)),
+        OutputFormat::Json => to_json(&BenchReport { schema_version: SCHEMA_VERSION, bench: &records }),
+        OutputFormat::Csv => to_csv(records.iter().flat_map(|record| {
+            [("read", record.read), ("parse", record.parse), ("solve", record.solve), ("total", record.total)].map(|(phase, stats)| {
+                BenchRow {
+                    year: record.year,
+                    day: record.day,
+                    part: record.part,
+                    status: record.status,
+                    runs: record.runs,
+                    phase,
+                    min_ns: stats.min_ns,
+                    median_ns: stats.median_ns,
+                    max_ns: stats.max_ns,
+                    error_kind: record.error.as_ref().map(|error| { error.kind }),
+                    error: record.error.as_ref().map(|error| { error.chain.join(": ") })
+                }
+            })
+        }))
+    }
+}
+
+fn to_json<T: Serialize>(report: &T) -> Result<String, ReportError> {
+    serde_json::to_string_pretty(report).map_err(|e| { ReportError::JsonError { source: e } })
+}
+
+fn to_csv<T: Serialize, I: IntoIterator<Item = T>>(rows: I) -> Result<String, ReportError> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for row in rows {
+        writer.serialize(row).map_err(|e| { ReportError::CsvError { source: e } })?;
+    }
+
+    let bytes = writer.into_inner().map_err(|e| { ReportError::CsvError { source: csv::Error::from(e.into_error()) } })?;
+    let csv = String::from_utf8(bytes).map_err(|e| { ReportError::Utf8Error { source: e } })?;
+    return Ok(csv.trim_end().to_string())
+}
+
+fn error_kind(error: &DayError) -> &'static str {
+    match error {
+        DayError::InvalidDay { .. } => "InvalidDay",
+        DayError::InputError { .. } => "InputError",
+        DayError::InternalDayError { .. } => "InternalDayError"
+    }
+}
+
+fn status_name(status: &RunStatus) -> &'static str {
+    match status {
+        RunStatus::Solved(_) => "ok",
+        RunStatus::Failed(_) => "error",
+        RunStatus::Panicked(_) => "panic",
+        RunStatus::Skipped(_) => "skipped"
+    }
+}
+
+fn verdict_name(verdict: &Verdict) -> &'static str {
+    match verdict {
+        Verdict::Pass => "pass",
+        Verdict::Fail { .. } => "fail",
+        Verdict::Unknown => "unknown"
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+#[derive(Error, Debug)]
+pub enum ReportError {
+    #[error("Failed to write JSON report")]
+    JsonError {
+        source: serde_json::Error
+    },
+    #[error("Failed to write CSV report")]
+    CsvError {
+        source: csv::Error
+    },
+    #[error("CSV report is not valid UTF-8")]
+    Utf8Error {
+        source: FromUtf8Error
+    }
+}
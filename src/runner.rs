@@ -0,0 +1,400 @@
+use std::{panic::{self, AssertUnwindSafe}, sync::atomic::{AtomicUsize, Ordering}, thread, time::{Duration, Instant}};
+
+use log::{debug, info};
+
+use crate::{answers::{Answers, Verdict}, days::{Answer, DayError, InputSource, registry::{Solver, SolverKey}}};
+
+/// How a single solver run ended.
+#[derive(Debug)]
+pub enum RunStatus {
+    Solved(Answer),
+    Failed(DayError),
+    Panicked(String),
+    /// The day's input file is not in the input directory yet, so there was nothing to run.
+    Skipped(DayError)
+}
+
+impl RunStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RunStatus::Solved(_) => "OK",
+            RunStatus::Failed(_) => "ERROR",
+            RunStatus::Panicked(_) => "PANIC",
+            RunStatus::Skipped(_) => "SKIPPED"
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, RunStatus::Solved(_))
+    }
+
+    /// Whether the run went wrong. Skipped runs are neither a success nor a failure.
+    pub fn is_failure(&self) -> bool {
+        matches!(self, RunStatus::Failed(_) | RunStatus::Panicked(_))
+    }
+
+    /// Turns a failure to open the day's file in the input directory into a skip, for runs over a range of days
+    /// that may not all have been fetched yet. A missing `--input` file is still a failure.
+    pub fn skip_missing_input(self, source: &InputSource) -> RunStatus {
+        match self {
+            RunStatus::Failed(error) if matches!(source, InputSource::Directory(_)) && error.is_missing_input() => RunStatus::Skipped(error),
+            status => status
+        }
+    }
+}
+
+/// Time spent in each phase of a solver run.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct PhaseTimings {
+    pub read: Duration,
+    pub parse: Duration,
+    pub solve: Duration
+}
+
+impl PhaseTimings {
+    pub fn total(&self) -> Duration {
+        self.read + self.parse + self.solve
+    }
+}
+
+/// Result of running one solver. When both parts of a day shared one parse, both carry the shared read and parse times.
+#[derive(Debug)]
+pub struct RunOutcome {
+    pub key: SolverKey,
+    pub status: RunStatus,
+    pub timings: PhaseTimings
+}
+
+impl RunOutcome {
+    /// Checks the answer against `answers`. Runs that did not produce an answer have no verdict.
+    pub fn verdict(&self, answers: &Answers) -> Option<Verdict> {
+        match &self.status {
+            RunStatus::Solved(answer) => Some(answers.verify(self.key.year, self.key.day, self.key.part, answer)),
+            _ => None
+        }
+    }
+}
+
+/// Minimum, median and maximum of a set of durations.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct DurationStats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration
+}
+
+impl DurationStats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        if samples.is_empty() {
+            return DurationStats::default()
+        }
+
+        samples.sort();
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        return DurationStats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1]
+        }
+    }
+}
+
+/// Result of running a solver repeatedly. `runs` only holds timings of successful runs.
+#[derive(Debug)]
+pub struct BenchOutcome {
+    pub key: SolverKey,
+    pub status: RunStatus,
+    pub runs: Vec<PhaseTimings>
+}
+
+impl BenchOutcome {
+    pub fn read_stats(&self) -> DurationStats {
+        DurationStats::from_samples(self.runs.iter().map(|run| { run.read }).collect())
+    }
+
+    pub fn parse_stats(&self) -> DurationStats {
+        DurationStats::from_samples(self.runs.iter().map(|run| { run.parse }).collect())
+    }
+
+    pub fn solve_stats(&self) -> DurationStats {
+        DurationStats::from_samples(self.runs.iter().map(|run| { run.solve }).collect())
+    }
+
+    pub fn total_stats(&self) -> DurationStats {
+        DurationStats::from_samples(self.runs.iter().map(|run| { run.total() }).collect())
+    }
+}
+
+pub struct Runner;
+
+impl Runner {
+    /// Runs one solver, turning both `DayError`s and panics into a `RunStatus` so callers can keep going.
+    pub fn run(solver: &Solver, source: &InputSource) -> RunOutcome {
+        info!("Running {}", solver.key);
+        let mut timings = PhaseTimings::default();
+        let status = match catch_failure(|| { solver.solve_timed(source, &mut timings) }) {
+            Ok(answer) => RunStatus::Solved(answer),
+            Err(status) => status
+        };
+
+        return RunOutcome {
+            key: solver.key,
+            status,
+            timings
+        }
+    }
+
+    /// Runs every solver, spreading days over up to `jobs` worker threads. Consecutive parts of a day that read
+    /// the same input share one read and parse. Outcomes come back in the order of `solvers` whatever `jobs` is.
+    pub fn run_all(solvers: &[Solver], source: &InputSource, jobs: usize) -> Vec<RunOutcome> {
+        let groups = solvers.chunk_by(|solver, next| { solver.shares_input(next, source) }).collect::<Vec<&[Solver]>>();
+        if jobs <= 1 || groups.len() <= 1 {
+            return groups.into_iter().flat_map(|group| { Self::run_shared(group, source) }).collect()
+        }
+
+        let next_group = AtomicUsize::new(0);
+        let mut finished = thread::scope(|scope| {
+            let workers = (0..jobs.min(groups.len())).map(|_| {
+                scope.spawn(|| {
+                    let mut finished = Vec::new();
+                    loop {
+                        let index = next_group.fetch_add(1, Ordering::Relaxed);
+                        let Some(group) = groups.get(index) else {
+                            return finished
+                        };
+                        finished.push((index, Self::run_shared(group, source)));
+                    }
+                })
+            }).collect::<Vec<_>>();
+
+            workers.into_iter()
+                .flat_map(|worker| { worker.join().expect("solver panics are caught inside the worker") })
+                .collect::<Vec<(usize, Vec<RunOutcome>)>>()
+        });
+
+        finished.sort_by_key(|(index, _)| { *index });
+        return finished.into_iter().flat_map(|(_, outcomes)| { outcomes }).collect()
+    }
+
+    fn run_shared(group: &[Solver], source: &InputSource) -> Vec<RunOutcome> {
+        if group.len() == 1 {
+            return vec![Self::run(&group[0], source)]
+        }
+
+        info!("Running {} Day {} with one parse shared by {} parts", group[0].key.year, group[0].key.day, group.len());
+
+        let mut shared_timings = PhaseTimings::default();
+        let parsed = catch_failure(|| {
+            let start = Instant::now();
+            let raw = group[0].read(source)?;
+            shared_timings.read = start.elapsed();
+
+            let start = Instant::now();
+            let input = group[0].parse(&raw)?;
+            shared_timings.parse = start.elapsed();
+            debug!("{} Day {} read in {} and parsed in {}", group[0].key.year, group[0].key.day, format_duration(shared_timings.read), format_duration(shared_timings.parse));
+
+            return Ok(input)
+        });
+
+        // Let each part report its own failure rather than sharing one error between them.
+        let Ok(input) = parsed else {
+            return group.iter().map(|solver| { Self::run(solver, source) }).collect()
+        };
+
+        return group.iter().map(|solver| {
+            let mut timings = shared_timings;
+            let start = Instant::now();
+            let status = match catch_failure(|| { solver.solve_parsed(&input) }) {
+                Ok(answer) => RunStatus::Solved(answer),
+                Err(status) => status
+            };
+            timings.solve = start.elapsed();
+
+            RunOutcome {
+                key: solver.key,
+                status,
+                timings
+            }
+        }).collect()
+    }
+
+    /// Runs a solver `iterations` times, stopping early at the first run that does not succeed.
+    pub fn bench(solver: &Solver, source: &InputSource, iterations: usize) -> BenchOutcome {
+        let mut runs = Vec::with_capacity(iterations);
+        let mut status = RunStatus::Solved(Answer::Text(String::new()));
+
+        for _ in 0..iterations {
+            let outcome = Self::run(solver, source);
+            status = outcome.status;
+            if !status.is_success() {
+                break
+            }
+            runs.push(outcome.timings);
+        }
+
+        return BenchOutcome {
+            key: solver.key,
+            status,
+            runs
+        }
+    }
+
+    /// Renders a summary table of year, day, part, answer, status, verdict and elapsed time, followed by any failure details.
+    pub fn summary(outcomes: &[RunOutcome], answers: &Answers) -> String {
+        let rows = outcomes.iter().map(|outcome| {
+            let answer = match &outcome.status {
+                RunStatus::Solved(answer) if answer.is_multiline() => String::from("(see below)"),
+                RunStatus::Solved(answer) => answer.to_string(),
+                _ => String::from("-")
+            };
+            let verdict = outcome.verdict(answers).map_or("-", |verdict| { verdict.label() });
+
+            vec![
+                outcome.key.year.to_string(),
+                outcome.key.day.to_string(),
+                outcome.key.part.to_string(),
+                answer,
+                outcome.status.label().to_string(),
+                verdict.to_string(),
+                format_duration(outcome.timings.read),
+                format_duration(outcome.timings.parse),
+                format_duration(outcome.timings.solve),
+                format_duration(outcome.timings.total())
+            ]
+        }).collect::<Vec<Vec<String>>>();
+
+        let mut lines = vec![render_table(&["Year", "Day", "Part", "Answer", "Status", "Check", "Read", "Parse", "Solve", "Time"], &rows)];
+        lines.extend(outcomes.iter().filter_map(|outcome| {
+            match &outcome.status {
+                RunStatus::Solved(answer) if answer.is_multiline() => Some(format!("{}:\n{}", outcome.key, answer)),
+                _ => None
+            }
+        }));
+        lines.extend(outcomes.iter().filter_map(|outcome| { failure_detail(outcome.key, &outcome.status) }));
+        lines.extend(outcomes.iter().filter_map(|outcome| {
+            match outcome.verdict(answers) {
+                Some(Verdict::Fail { expected }) if expected.is_multiline() => Some(format!("{}: expected\n{}", outcome.key, expected)),
+                Some(Verdict::Fail { expected }) => Some(format!("{}: expected {}", outcome.key, expected)),
+                _ => None
+            }
+        }));
+
+        return lines.join("\n")
+    }
+
+    /// Renders min/median/max of every phase for each benchmarked solver.
+    pub fn bench_summary(outcomes: &[BenchOutcome]) -> String {
+        let mut rows = Vec::new();
+        for outcome in outcomes.iter() {
+            let phases = [
+                ("read", outcome.read_stats()),
+                ("parse", outcome.parse_stats()),
+                ("solve", outcome.solve_stats()),
+                ("total", outcome.total_stats())
+            ];
+
+            for (phase, stats) in phases {
+                rows.push(vec![
+                    outcome.key.year.to_string(),
+                    outcome.key.day.to_string(),
+                    outcome.key.part.to_string(),
+                    outcome.runs.len().to_string(),
+                    phase.to_string(),
+                    format_duration(stats.min),
+                    format_duration(stats.median),
+                    format_duration(stats.max)
+                ]);
+            }
+        }
+
+        let mut lines = vec![render_table(&["Year", "Day", "Part", "Runs", "Phase", "Min", "Median", "Max"], &rows)];
+        lines.extend(outcomes.iter().filter_map(|outcome| { failure_detail(outcome.key, &outcome.status) }));
+
+        return lines.join("\n")
+    }
+}
+
+/// Runs `f`, turning both `DayError`s and panics into a failed `RunStatus` so callers can keep going.
+fn catch_failure<T, F: FnOnce() -> Result<T, DayError>>(f: F) -> Result<T, RunStatus> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(error)) => Err(RunStatus::Failed(error)),
+        Err(payload) => {
+            let message = payload.downcast_ref::<&str>().map(|message| { message.to_string() })
+                .or_else(|| { payload.downcast_ref::<String>().cloned() })
+                .unwrap_or_else(|| { String::from("unknown panic payload") });
+            Err(RunStatus::Panicked(message))
+        }
+    }
+}
+
+fn failure_detail(key: SolverKey, status: &RunStatus) -> Option<String> {
+    match status {
+        RunStatus::Failed(error) => Some(format!("{}: {}", key, error_chain(error))),
+        RunStatus::Panicked(message) => Some(format!("{}: panicked: {}", key, message)),
+        RunStatus::Skipped(error) => Some(format!("{}: skipped: {}", key, error_chain(error))),
+        RunStatus::Solved(_) => None
+    }
+}
+
+/// Lays out `rows` under `header` in left-aligned, `|`-separated columns.
+fn render_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths = header.iter().map(|column| { column.len() }).collect::<Vec<usize>>();
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |row: Vec<&str>| {
+        row.into_iter().zip(widths.iter())
+            .map(|(cell, width)| { format!("{:<width$}", cell, width = width) })
+            .collect::<Vec<String>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut table = vec![
+        format_row(header.to_vec()),
+        widths.iter().map(|width| { "-".repeat(*width) }).collect::<Vec<String>>().join("-+-")
+    ];
+    table.extend(rows.iter().map(|row| { format_row(row.iter().map(String::as_str).collect()) }));
+
+    return table.join("\n")
+}
+
+/// Joins an error with all of its sources, e.g. `outer: middle: inner`.
+pub fn error_chain(error: &dyn std::error::Error) -> String {
+    source_chain(error).join(": ")
+}
+
+/// The error's message followed by the message of each of its sources, outermost first.
+pub fn source_chain(error: &dyn std::error::Error) -> Vec<String> {
+    let mut chain = vec![error.to_string()];
+    let mut source = error.source();
+    while let Some(cause) = source {
+        chain.push(cause.to_string());
+        source = cause.source();
+    }
+
+    return chain
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+    return if micros < 1_000.0 {
+        format!("{:.1}µs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.3}ms", micros / 1_000.0)
+    } else {
+        format!("{:.3}s", micros / 1_000_000.0)
+    }
+}
@@ -0,0 +1,192 @@
+use std::{fs, io, path::{Path, PathBuf}};
+
+use thiserror::Error;
+
+/// The year Advent of Code started.
+const FIRST_YEAR: u16 = 2015;
+
+/// Skeleton of a new day's module. `{{YEAR}}` and `{{DAY}}` are replaced with the year and day number.
+const DAY_TEMPLATE: &str = include_str!("templates/day.rs.template");
+
+/// Opening of the macro call in `tests/examples.rs` that lists every day with examples. It starts a line,
+/// unlike mentions of the macro in comments.
+const EXAMPLE_TESTS_MACRO: &str = "\nexample_tests! {";
+
+/// The files a new day touches, relative to the crate root.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ScaffoldPaths {
+    pub module: PathBuf,
+    pub year_mod: PathBuf,
+    pub days_mod: PathBuf,
+    pub example_tests: PathBuf
+}
+
+impl ScaffoldPaths {
+    pub fn new(root: &Path, year: u16, day: u8) -> Self {
+        let days_dir = root.join("src").join("days");
+        let year_dir = days_dir.join(format!("y{}", year));
+
+        ScaffoldPaths {
+            module: year_dir.join(format!("day{}.rs", day)),
+            year_mod: year_dir.join("mod.rs"),
+            days_mod: days_dir.join("mod.rs"),
+            example_tests: root.join("tests").join("examples.rs")
+        }
+    }
+}
+
+/// Writes `src/days/y{year}/day{day}.rs` from the template, declares it in the year's module, starting that module
+/// if this is the year's first day, and adds its example test. Nothing is written unless every file can be updated.
+pub fn scaffold_day(root: &Path, year: u16, day: u8) -> Result<ScaffoldPaths, ScaffoldError> {
+    if !(1..=25).contains(&day) {
+        return Err(ScaffoldError::InvalidDayError { day })
+    }
+    if year < FIRST_YEAR {
+        return Err(ScaffoldError::InvalidYearError { year })
+    }
+
+    let paths = ScaffoldPaths::new(root, year, day);
+    if paths.module.exists() {
+        return Err(ScaffoldError::ModuleExistsError { file_path: paths.module })
+    }
+
+    let year_mod = if paths.year_mod.exists() {
+        add_module_declaration(&read(&paths.year_mod)?, day)
+            .ok_or_else(|| { ScaffoldError::MissingAnchorError { file_path: paths.year_mod.clone(), anchor: "pub mod dayN;" } })?
+    } else {
+        render_year_module(year, day)
+    };
+    let days_mod = add_year_declaration(&read(&paths.days_mod)?, year)
+        .ok_or_else(|| { ScaffoldError::MissingAnchorError { file_path: paths.days_mod.clone(), anchor: "pub mod yYYYY;" } })?;
+    let example_tests = add_example_test(&read(&paths.example_tests)?, year, day)
+        .ok_or_else(|| { ScaffoldError::MissingAnchorError { file_path: paths.example_tests.clone(), anchor: EXAMPLE_TESTS_MACRO.trim_start() } })?;
+
+    if let Some(year_dir) = paths.module.parent() {
+        fs::create_dir_all(year_dir).map_err(|e| {
+            ScaffoldError::WriteError {
+                file_path: year_dir.to_path_buf(),
+                source: e
+            }
+        })?;
+    }
+    write(&paths.module, &render_module(year, day))?;
+    write(&paths.year_mod, &year_mod)?;
+    write(&paths.days_mod, &days_mod)?;
+    write(&paths.example_tests, &example_tests)?;
+
+    return Ok(paths)
+}
+
+pub fn render_module(year: u16, day: u8) -> String {
+    DAY_TEMPLATE.replace("{{YEAR}}", &year.to_string()).replace("{{DAY}}", &day.to_string())
+}
+
+/// The module of a year that has no days yet, declaring its first one.
+pub fn render_year_module(year: u16, day: u8) -> String {
+    format!("//! Solutions to Advent of Code {}.\n\npub mod day{};\n", year, day)
+}
+
+/// Adds `pub mod day{day};` among the other day modules of a year, keeping them in day order.
+/// Returns `None` when there is no day module to anchor it to.
+pub fn add_module_declaration(year_mod: &str, day: u8) -> Option<String> {
+    add_numbered_module(year_mod, "day", u16::from(day))
+}
+
+/// Adds `pub mod y{year};` among the other year modules, keeping them in year order.
+/// Returns `None` when there is no year module to anchor it to.
+pub fn add_year_declaration(days_mod: &str, year: u16) -> Option<String> {
+    add_numbered_module(days_mod, "y", year)
+}
+
+/// Adds `({year}, {day})` to the `example_tests!` call, keeping its entries in year and day order.
+/// Returns `None` when the macro call is not there.
+pub fn add_example_test(example_tests: &str, year: u16, day: u8) -> Option<String> {
+    let start = example_tests.find(EXAMPLE_TESTS_MACRO)? + EXAMPLE_TESTS_MACRO.len();
+    let end = start + example_tests[start..].find("\n}")?;
+
+    let mut entries = example_tests[start..end].split("),")
+        .filter_map(|entry| { entry.split_once("=>") })
+        .map(|(_, key)| { example_key(key) })
+        .collect::<Option<Vec<(u16, u8)>>>()?;
+    entries.push((year, day));
+    entries.sort();
+    entries.dedup();
+
+    let entries = entries.iter()
+        .map(|(entry_year, entry_day)| { format!("    y{}_day{} => ({}, {})", entry_year, entry_day, entry_year, entry_day) })
+        .collect::<Vec<String>>();
+    return Some(format!("{}\n{}{}", &example_tests[..start], entries.join(",\n"), &example_tests[end..]))
+}
+
+/// Parses the `(year, day)` an `example_tests!` entry points at.
+fn example_key(key: &str) -> Option<(u16, u8)> {
+    let (year, day) = key.trim().trim_start_matches('(').trim_end_matches(')').split_once(',')?;
+    return Some((year.trim().parse().ok()?, day.trim().parse().ok()?))
+}
+
+/// Adds `pub mod {prefix}{number};` after the last module with a smaller number, or before the first with a larger one.
+fn add_numbered_module(contents: &str, prefix: &str, number: u16) -> Option<String> {
+    let declaration = format!("pub mod {}{};", prefix, number);
+    let mut lines = contents.lines().collect::<Vec<&str>>();
+    if lines.contains(&declaration.as_str()) {
+        return Some(contents.to_string())
+    }
+
+    let modules = lines.iter().enumerate()
+        .filter_map(|(index, line)| { module_number(line, prefix).map(|module_number| { (index, module_number) }) })
+        .collect::<Vec<(usize, u16)>>();
+    let (last_index, _) = *modules.last()?;
+    let insert_at = modules.iter()
+        .find(|(_, module_number)| { *module_number > number })
+        .map_or(last_index + 1, |(index, _)| { *index });
+
+    lines.insert(insert_at, &declaration);
+    return Some(lines.join("\n") + "\n")
+}
+
+fn module_number(line: &str, prefix: &str) -> Option<u16> {
+    line.strip_prefix("pub mod ")?.strip_prefix(prefix)?.strip_suffix(';')?.parse().ok()
+}
+
+fn read(file_path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(file_path).map_err(|e| {
+        ScaffoldError::ReadError {
+            file_path: file_path.to_path_buf(),
+            source: e
+        }
+    })
+}
+
+fn write(file_path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    fs::write(file_path, contents).map_err(|e| {
+        ScaffoldError::WriteError {
+            file_path: file_path.to_path_buf(),
+            source: e
+        }
+    })
+}
+
+#[derive(Error, Debug)]
+pub enum ScaffoldError {
+    #[error("Day {day} is not an Advent of Code day")]
+    InvalidDayError { day: u8 },
+    #[error("There was no Advent of Code in {year}")]
+    InvalidYearError { year: u16 },
+    #[error("{} already exists", file_path.display())]
+    ModuleExistsError { file_path: PathBuf },
+    #[error("Could not find `{anchor}` in {} to add the new day next to", file_path.display())]
+    MissingAnchorError {
+        file_path: PathBuf,
+        anchor: &'static str
+    },
+    #[error("Failed to read {}", file_path.display())]
+    ReadError {
+        file_path: PathBuf,
+        source: io::Error
+    },
+    #[error("Failed to write {}", file_path.display())]
+    WriteError {
+        file_path: PathBuf,
+        source: io::Error
+    }
+}
@@ -0,0 +1,173 @@
+use std::{io, time::Duration};
+
+use log::{debug, info};
+use thiserror::Error;
+
+use crate::{days::{Answer, Part}, fetch::{Client, MissingSessionError}, guesses::GuessResult};
+
+/// What the server made of a submitted answer.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SubmitOutcome {
+    Correct,
+    /// The answer was wrong. `wait` is how long the server refuses further answers for.
+    Wrong {
+        result: GuessResult,
+        wait: Option<Duration>
+    },
+    /// The answer was not looked at because the previous one was too recent.
+    TooSoon { wait: Duration },
+    /// The part is already solved or not unlocked yet, so the answer was not looked at.
+    WrongLevel
+}
+
+/// Posts answers with a logged-in session.
+pub struct Submitter {
+    client: Client
+}
+
+impl Submitter {
+    pub fn new(base_url: &str, session: Option<&str>) -> Self {
+        Submitter { client: Client::new(base_url, session) }
+    }
+
+    pub fn answer_url(&self, year: u16, day: u8) -> String {
+        self.client.day_url(year, day, "answer")
+    }
+
+    /// Submits `answer`. Nothing is checked against earlier guesses here, see `Guesses::check`.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Result<SubmitOutcome, SubmitError> {
+        let url = self.answer_url(year, day);
+        let request = self.client.request("POST", &url)?;
+        info!("Submitting {} for Day {}{} to {}", answer, day, part.suffix(), url);
+
+        let response = request.send_form(&[("level", &part.to_string()), ("answer", &answer.to_string())])
+            .map_err(|e| {
+                match e {
+                    ureq::Error::Status(status, _) => SubmitError::StatusError {
+                        url: url.clone(),
+                        status
+                    },
+                    ureq::Error::Transport(transport) => SubmitError::RequestError {
+                        url: url.clone(),
+                        source: Box::new(transport)
+                    }
+                }
+            })?;
+
+        let body = response.into_string().map_err(|e| {
+            SubmitError::ReadError {
+                url,
+                source: e
+            }
+        })?;
+
+        return parse_response(&body)
+    }
+}
+
+/// Reads the verdict out of the page the server answers a submission with.
+pub fn parse_response(body: &str) -> Result<SubmitOutcome, SubmitError> {
+    let message = article_text(body);
+    debug!("Server said: {}", message);
+
+    return if message.contains("That's the right answer") {
+        Ok(SubmitOutcome::Correct)
+    } else if message.contains("That's not the right answer") {
+        let result = if message.contains("too high") {
+            GuessResult::TooHigh
+        } else if message.contains("too low") {
+            GuessResult::TooLow
+        } else {
+            GuessResult::Wrong
+        };
+        Ok(SubmitOutcome::Wrong { result, wait: minutes_to_wait(&message) })
+    } else if message.contains("You gave an answer too recently") {
+        let wait = time_left(&message).ok_or_else(|| { SubmitError::UnrecognisedResponseError { message: message.clone() } })?;
+        Ok(SubmitOutcome::TooSoon { wait })
+    } else if message.contains("You don't seem to be solving the right level") {
+        Ok(SubmitOutcome::WrongLevel)
+    } else {
+        Err(SubmitError::UnrecognisedResponseError { message })
+    }
+}
+
+/// Text of the page's `<article>`, or of the whole page if there is none, without tags and with collapsed whitespace.
+fn article_text(body: &str) -> String {
+    let article = body.find("<article")
+        .and_then(|start| { body[start..].find('>').map(|end| { &body[start + end + 1..] }) })
+        .map_or(body, |article| { article.split_once("</article>").map_or(article, |(article, _)| { article }) });
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => ()
+        }
+    }
+
+    return text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Parses "please wait one minute" or "please wait 5 minutes" out of a wrong answer message.
+fn minutes_to_wait(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("wait ")?;
+    let count = rest.split_whitespace().next()?;
+    let minutes = match count {
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "ten" => 10,
+        number => number.parse::<u64>().ok()?
+    };
+    return Some(Duration::from_secs(minutes * 60))
+}
+
+/// Parses "You have 1m 23s left to wait" into a duration.
+fn time_left(message: &str) -> Option<Duration> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (left, _) = rest.split_once(" left to wait")?;
+
+    let mut seconds = 0;
+    for amount in left.split_whitespace() {
+        let (value, unit) = amount.split_at(amount.find(|c: char| { !c.is_ascii_digit() })?);
+        let value = value.parse::<u64>().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None
+        };
+    }
+    return Some(Duration::from_secs(seconds))
+}
+
+#[derive(Error, Debug)]
+pub enum SubmitError {
+    #[error(transparent)]
+    MissingSessionError {
+        #[from]
+        source: MissingSessionError
+    },
+    #[error("Request to {url} failed")]
+    RequestError {
+        url: String,
+        source: Box<ureq::Transport>
+    },
+    #[error("{url} answered {status}")]
+    StatusError {
+        url: String,
+        status: u16
+    },
+    #[error("Failed to read the response from {url}")]
+    ReadError {
+        url: String,
+        source: io::Error
+    },
+    #[error("Did not understand the server's response: {message}")]
+    UnrecognisedResponseError { message: String }
+}
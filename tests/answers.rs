@@ -0,0 +1,37 @@
+use aoc::{answers::{Answers, Verdict}, days::{Answer, Part}};
+
+#[test]
+fn integer_answers_match_expected_answers_written_as_text() {
+    assert!(Answer::from(24000u64).matches(&Answer::from("24000")));
+    assert!(Answer::from("24000").matches(&Answer::from(24000u32)));
+    assert!(!Answer::from(24000u64).matches(&Answer::from(24001u64)));
+}
+
+#[test]
+fn integers_too_wide_for_toml_are_kept_as_text() {
+    assert_eq!(Answer::from(u64::MAX), Answer::Text(u64::MAX.to_string()));
+}
+
+#[test]
+fn grids_display_one_row_per_line() {
+    let grid = Answer::grid(["#..#", "####"]);
+    assert!(grid.is_multiline());
+    assert_eq!(grid.to_string(), "#..#\n####");
+}
+
+#[test]
+fn typed_answers_round_trip_through_toml() {
+    let mut answers = Answers::default();
+    answers.record(2022, 1, Part::One, Answer::from(72017u64));
+    answers.record(2022, 1, Part::Two, Answer::from("ZWHVFWQWW"));
+    answers.record(2016, 8, Part::One, Answer::grid(["#.", ".#"]));
+
+    let contents = answers.to_toml().expect("answers serialize");
+    assert!(contents.contains("[2022.day1]\npart1 = 72017"), "{}", contents);
+
+    let reloaded = Answers::from_toml(&contents).expect("answers parse");
+    assert_eq!(reloaded, answers);
+    assert_eq!(reloaded.verify(2016, 8, Part::One, &Answer::grid(["#.", ".#"])), Verdict::Pass);
+    assert_eq!(reloaded.verify(2022, 8, Part::One, &Answer::grid(["#.", ".#"])), Verdict::Unknown);
+    assert_eq!(reloaded.verify(2016, 8, Part::Two, &Answer::from(1u8)), Verdict::Unknown);
+}
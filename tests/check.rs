@@ -0,0 +1,64 @@
+use aoc::{check::{check_day, InputCheck, InputIssue}, days::{DayError, InputSource, Part}};
+
+fn check_text(day: u8, input: &str) -> InputCheck {
+    let mut checks = check_day(2022, day, &InputSource::Text(input.to_string())).expect("day is registered");
+    assert_eq!(checks.len(), 1, "both parts read the same input");
+    checks.remove(0)
+}
+
+#[test]
+fn both_parts_of_a_day_share_one_check() {
+    let check = check_text(2, "A Y\nB X\nC Z\nA X\n");
+
+    assert_eq!(check.parts, vec![Part::One, Part::Two]);
+    assert_eq!((check.lines, check.groups), (4, 1));
+    assert_eq!(check.description.as_deref(), Some("4 rounds of Rock Paper Scissors"));
+    assert!(check.issues.is_empty(), "{:?}", check.issues);
+    assert!(check.is_ok());
+}
+
+#[test]
+fn layout_problems_are_only_warnings() {
+    let check = check_text(1, "\r\n1000\r\n\r\n\r\n2000\r\n3000");
+
+    assert_eq!((check.lines, check.groups), (6, 2));
+    assert_eq!(check.description.as_deref(), Some("2 Elves carrying 3 items of food"));
+    assert_eq!(check.issues, vec![
+        InputIssue::CrlfLineEndings { lines: 5 },
+        InputIssue::MissingTrailingNewline,
+        InputIssue::LeadingBlankLines { count: 1 },
+        InputIssue::RepeatedBlankLines { line: 3 }
+    ]);
+    assert!(check.is_ok());
+}
+
+#[test]
+fn invalid_characters_fail_the_day_parser() {
+    let check = check_text(2, "A Y\nB W \nC Z\n");
+
+    assert!(!check.is_ok());
+    assert_eq!(check.description, None);
+    assert!(matches!(&check.issues[0], InputIssue::ParseFailed { message } if message.contains("line 2, column 3: expected X, Y or Z")), "{:?}", check.issues);
+    assert_eq!(check.issues[1], InputIssue::TrailingWhitespace { line: 2, count: 1 });
+}
+
+#[test]
+fn examples_and_empty_inputs_are_flagged() {
+    let example = check_text(2, "A Y\r\nB X\r\nC Z");
+    assert!(example.issues.contains(&InputIssue::LooksLikeExample), "{:?}", example.issues);
+
+    let empty = check_text(1, "");
+    assert_eq!(empty.issues, vec![InputIssue::Empty]);
+    assert!(!empty.is_ok());
+
+    assert!(matches!(check_day(2022, 26, &InputSource::Text(String::new())), Err(DayError::InvalidDay { year: 2022, day: 26 })));
+}
+
+#[test]
+fn padded_rows_of_positional_inputs_are_not_flagged() {
+    let padded = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2\n";
+    let check = check_text(5, padded);
+
+    assert_eq!(check.description.as_deref(), Some("3 stacks of 6 crates, rearranged in 4 steps"));
+    assert!(check.issues.is_empty(), "{:?}", check.issues);
+}
@@ -0,0 +1,64 @@
+// Each test file compiles its own copy of these helpers and none of them uses all of them.
+#![allow(dead_code)]
+
+use std::{fs, io::{BufRead, BufReader, Read, Write}, net::TcpListener, path::PathBuf, sync::{Arc, Mutex}, thread};
+
+/// An empty directory under the system temp dir, unique to this test process, `group` and `name`.
+pub fn scratch_dir(group: &str, name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-{}-{}-{}", group, name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// A throwaway HTTP server on localhost that answers every request with `status` and `body`
+/// and remembers each request it was sent: request line, headers and body.
+pub struct MockServer {
+    pub base_url: String,
+    requests: Arc<Mutex<Vec<String>>>
+}
+
+impl MockServer {
+    pub fn start(status: &'static str, body: &'static str) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind mock server");
+        let base_url = format!("http://{}", listener.local_addr().expect("mock server address"));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.expect("accept connection");
+                let mut reader = BufReader::new(stream.try_clone().expect("clone stream"));
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).expect("read request") == 0 || line == "\r\n" {
+                        break
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().expect("numeric content length");
+                        }
+                    }
+                    request.push_str(&line);
+                }
+
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).expect("read request body");
+                request.push_str("\r\n");
+                request.push_str(&String::from_utf8_lossy(&request_body));
+                recorded.lock().unwrap().push(request);
+
+                let response = format!("HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body);
+                stream.write_all(response.as_bytes()).expect("write response");
+            }
+        });
+
+        MockServer { base_url, requests }
+    }
+
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
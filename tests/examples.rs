@@ -0,0 +1,53 @@
+use aoc::days::{InputSource, Part, registry::Registry};
+
+/// Runs every example a day declares for both parts through the registered solver.
+fn check_examples(year: u16, day: u8) {
+    for part in [Part::One, Part::Two] {
+        let solver = Registry::get(year, day, part)
+            .unwrap_or_else(|| { panic!("{} Day {} part {} is not registered", year, day, part) });
+        let examples = solver.solution.examples.iter()
+            .filter_map(|example| { example.answer(part).map(|expected| { (example.input, expected) }) })
+            .collect::<Vec<(&str, &str)>>();
+        assert!(!examples.is_empty(), "{} declares no examples for part {}", solver.name(), part);
+
+        for (index, (input, expected)) in examples.into_iter().enumerate() {
+            let answer = solver.solve(&InputSource::Text(input.to_string()));
+            match answer {
+                Ok(answer) => assert_eq!(answer.to_string(), expected, "{} part {} example #{}", solver.name(), part, index),
+                Err(error) => panic!("{} part {} example #{} failed: {:?}", solver.name(), part, index, error)
+            }
+        }
+    }
+}
+
+/// Generates one test per day, e.g. `example_tests! { y2022_day1 => (2022, 1) }` creates `y2022_day1`.
+macro_rules! example_tests {
+    ($($name:ident => ($year:expr, $day:expr)),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                check_examples($year, $day);
+            }
+        )*
+    };
+}
+
+example_tests! {
+    y2022_day1 => (2022, 1),
+    y2022_day2 => (2022, 2),
+    y2022_day3 => (2022, 3),
+    y2022_day4 => (2022, 4),
+    y2022_day5 => (2022, 5),
+    y2022_day6 => (2022, 6),
+    y2022_day7 => (2022, 7)
+}
+
+#[test]
+fn every_registered_solver_declares_examples() {
+    let missing = Registry::all().into_iter()
+        .filter(|solver| { solver.solution.examples.iter().all(|example| { example.answer(solver.key.part).is_none() }) })
+        .map(|solver| { format!("{} part {}", solver.name(), solver.key.part) })
+        .collect::<Vec<String>>();
+
+    assert!(missing.is_empty(), "Solvers without examples: {:?}", missing);
+}
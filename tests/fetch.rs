@@ -0,0 +1,64 @@
+mod common;
+
+use std::fs;
+
+use aoc::fetch::{FetchError, FetchOutcome, Fetcher};
+
+use common::{scratch_dir, MockServer};
+
+#[test]
+fn downloads_input_with_session_cookie_into_the_day_file() {
+    let server = MockServer::start("200 OK", "1000\n2000\n");
+    let input_dir = scratch_dir("fetch", "download");
+    let fetcher = Fetcher::new(&server.base_url, Some("secret-token"));
+
+    let outcome = fetcher.fetch(2022, 1, &input_dir).expect("fetch succeeds");
+
+    assert_eq!(outcome, FetchOutcome::Downloaded(input_dir.join("2022").join("1.txt")));
+    assert_eq!(fs::read_to_string(input_dir.join("2022").join("1.txt")).unwrap(), "1000\n2000\n");
+    assert!(!input_dir.join("2022").join("1a.txt").exists());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1"), "{}", requests[0]);
+    assert!(requests[0].contains("session=secret-token"), "{}", requests[0]);
+}
+
+#[test]
+fn cached_inputs_are_never_downloaded_again() {
+    let server = MockServer::start("200 OK", "fresh\n");
+    let input_dir = scratch_dir("fetch", "cached");
+    fs::create_dir_all(input_dir.join("2022")).unwrap();
+    fs::write(input_dir.join("2022").join("2.txt"), "cached\n").unwrap();
+
+    // No session is needed when nothing has to be downloaded.
+    let fetcher = Fetcher::new(&server.base_url, None);
+    assert_eq!(fetcher.fetch(2022, 2, &input_dir).expect("cached"), FetchOutcome::Cached(input_dir.join("2022").join("2.txt")));
+    assert_eq!(fs::read_to_string(input_dir.join("2022").join("2.txt")).unwrap(), "cached\n");
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn downloading_without_a_session_fails_before_any_request() {
+    let server = MockServer::start("200 OK", "unused\n");
+    let fetcher = Fetcher::new(&server.base_url, Some("  "));
+
+    assert!(matches!(fetcher.fetch(2022, 3, &scratch_dir("fetch", "no-session")), Err(FetchError::MissingSessionError { .. })));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn error_statuses_are_reported_and_nothing_is_written() {
+    let server = MockServer::start("404 Not Found", "Please don't repeatedly request this endpoint before it unlocks!\n");
+    let input_dir = scratch_dir("fetch", "locked");
+    let fetcher = Fetcher::new(&server.base_url, Some("secret-token"));
+
+    match fetcher.fetch(2022, 25, &input_dir) {
+        Err(FetchError::StatusError { status, message, .. }) => {
+            assert_eq!(status, 404);
+            assert!(message.contains("before it unlocks"), "{}", message);
+        },
+        other => panic!("expected a status error, got {:?}", other)
+    }
+    assert!(!input_dir.join("2022").join("25.txt").exists());
+}
@@ -0,0 +1,52 @@
+mod common;
+
+use std::fs;
+
+use aoc::migrate::{apply_migration, plan_migration, MigrateError, MigrationStep};
+
+use common::scratch_dir;
+
+#[test]
+fn old_inputs_become_one_file_per_day() {
+    let input_dir = scratch_dir("migrate", "layout");
+    fs::write(input_dir.join("1.txt"), "one\n").unwrap();
+    fs::write(input_dir.join("1a.txt"), "one\n").unwrap();
+    fs::write(input_dir.join("2.txt"), "two\n").unwrap();
+    fs::write(input_dir.join("2a.txt"), "two, differently\n").unwrap();
+    fs::write(input_dir.join("3a.txt"), "three\n").unwrap();
+    fs::write(input_dir.join("answers.toml"), "").unwrap();
+    fs::create_dir_all(input_dir.join("2021")).unwrap();
+    fs::write(input_dir.join("2021").join("5.txt"), "five\n").unwrap();
+    fs::write(input_dir.join("2021").join("5a.txt"), "five\n").unwrap();
+
+    let year_dir = input_dir.join("2022");
+    let steps = plan_migration(&input_dir, 2022).expect("plan succeeds");
+    assert_eq!(steps, vec![
+        MigrationStep::Move { from: input_dir.join("1.txt"), to: year_dir.join("1.txt") },
+        MigrationStep::Remove { path: input_dir.join("1a.txt"), duplicate_of: year_dir.join("1.txt") },
+        MigrationStep::Move { from: input_dir.join("2.txt"), to: year_dir.join("2.txt") },
+        MigrationStep::Move { from: input_dir.join("2a.txt"), to: year_dir.join("2.part2.txt") },
+        MigrationStep::Move { from: input_dir.join("3a.txt"), to: year_dir.join("3.txt") },
+        MigrationStep::Remove { path: input_dir.join("2021").join("5a.txt"), duplicate_of: input_dir.join("2021").join("5.txt") }
+    ]);
+
+    apply_migration(&steps).expect("migration succeeds");
+    assert_eq!(fs::read_to_string(year_dir.join("1.txt")).unwrap(), "one\n");
+    assert_eq!(fs::read_to_string(year_dir.join("2.part2.txt")).unwrap(), "two, differently\n");
+    assert_eq!(fs::read_to_string(year_dir.join("3.txt")).unwrap(), "three\n");
+    assert!(!input_dir.join("1a.txt").exists() && !input_dir.join("2021").join("5a.txt").exists());
+    assert!(input_dir.join("answers.toml").exists());
+
+    assert_eq!(plan_migration(&input_dir, 2022).expect("plan succeeds"), vec![]);
+}
+
+#[test]
+fn differing_inputs_for_the_same_file_are_left_alone() {
+    let input_dir = scratch_dir("migrate", "conflict");
+    fs::create_dir_all(input_dir.join("2022")).unwrap();
+    fs::write(input_dir.join("1.txt"), "old\n").unwrap();
+    fs::write(input_dir.join("2022").join("1.txt"), "new\n").unwrap();
+
+    assert!(matches!(plan_migration(&input_dir, 2022), Err(MigrateError::ConflictError { .. })));
+    assert!(input_dir.join("1.txt").exists());
+}
@@ -0,0 +1,154 @@
+use aoc::{days::{Answer, DayError, InputSource, Part, registry::Registry}, parser::{lines, parse_input, InputError, ParseFailure, ParseResult}, runner::error_chain};
+use nom::{character::complete::{char, digit1, u8}, error::context, sequence::separated_pair};
+
+fn parse_pair(input: &str) -> ParseResult<'_, (&str, &str)> {
+    separated_pair(context("a number", digit1), char(','), context("a second number", digit1))(input)
+}
+
+#[test]
+fn failures_point_at_the_line_and_column_they_happened_on() {
+    let failure = ParseFailure::new("abc\r\nde\tfg\nhij", 8, String::from("a letter"));
+
+    assert_eq!((failure.line, failure.column), (2, 4));
+    assert_eq!(failure.text, "de\tfg");
+    assert_eq!(failure.annotated_line(), " 2 | de\tfg\n   |   \t^");
+}
+
+#[test]
+fn nom_errors_say_what_was_expected_where() {
+    let Err(InputError::ParseError { what, source }) = parse_input("12,x4", "pairs", parse_pair) else {
+        panic!("parsing should fail")
+    };
+
+    assert_eq!(what, "pairs");
+    assert_eq!(source, ParseFailure { line: 1, column: 4, text: String::from("12,x4"), expected: String::from("a second number") });
+    assert_eq!(source.to_string(), "line 1, column 4: expected a second number\n 1 | 12,x4\n   |    ^");
+
+    let Err(InputError::ParseError { source, .. }) = parse_input("12;34", "pairs", parse_pair) else {
+        panic!("parsing should fail")
+    };
+    assert_eq!(source.expected, "','");
+}
+
+#[test]
+fn day_parse_errors_end_with_the_annotated_line() {
+    let solver = Registry::get(2022, 1, Part::One).expect("day 1 is registered");
+    let error = solver.solve(&InputSource::Text(String::from("1000\n\nabc\n"))).expect_err("calories must be numbers");
+
+    assert!(error_chain(&error).ends_with("line 3, column 1: expected a number of Calories or a blank line\n 3 | abc\n   | ^"), "{}", error_chain(&error));
+}
+
+#[test]
+fn every_line_has_to_parse_whatever_its_line_endings() {
+    assert_eq!(parse_input("1\r\n2\n3", "numbers", lines(u8)).unwrap(), vec![1, 2, 3]);
+    assert_eq!(parse_input("1\n2\n", "numbers", lines(u8)).unwrap(), vec![1, 2]);
+
+    let Err(InputError::ParseError { source, .. }) = parse_input("1\n2x\n3\n", "numbers", lines(u8)) else {
+        panic!("a corrupted line should fail")
+    };
+    assert_eq!((source.line, source.column, source.expected.as_str()), (2, 2, "the end of the line"));
+
+    let Err(InputError::ParseError { source, .. }) = parse_input("1,2 and more", "pairs", parse_pair) else {
+        panic!("leftover input should fail")
+    };
+    assert_eq!((source.line, source.column, source.expected.as_str()), (1, 4, "end of input"));
+}
+
+#[test]
+fn days_read_the_last_line_without_a_final_newline() {
+    let solve = |day: u8, input: &str| {
+        Registry::get(2022, day, Part::One).expect("day is registered").solve(&InputSource::Text(input.to_string()))
+    };
+
+    assert_eq!(solve(1, "1000\r\n2000\r\n\r\n4000").unwrap(), Answer::Integer(4000));
+    assert_eq!(solve(2, "A Y\nB X\nC Z").unwrap(), Answer::Integer(15));
+    assert!(solve(2, "A Y\nB Q\nC Z\n").is_err());
+}
+
+#[test]
+fn day3_rejects_uneven_rucksacks_and_groups_without_one_badge() {
+    let solve = |part: Part, input: &str| {
+        Registry::get(2022, 3, part).expect("day 3 is registered").solve(&InputSource::Text(input.to_string()))
+    };
+
+    let error = solve(Part::One, "vJrwpWtwJgWrhcsFMMfFFhFp\nabc\n").expect_err("compartments hold the same number of items");
+    assert!(error_chain(&error).contains("line 2, column 1: expected an even number of items"), "{}", error_chain(&error));
+
+    let error = solve(Part::One, "abcd\n").expect_err("the compartments share nothing");
+    assert!(error_chain(&error).ends_with("Rucksack 1 has 0 item types in both compartments instead of exactly one"), "{}", error_chain(&error));
+
+    let error = solve(Part::Two, "aa\nbb\naa\nbb\n").expect_err("four rucksacks are not whole groups");
+    assert!(error_chain(&error).ends_with("4 rucksacks cannot be split into groups of three"), "{}", error_chain(&error));
+
+    let error = solve(Part::Two, "aa\nbb\ncc\n").expect_err("the group shares no item");
+    assert!(error_chain(&error).ends_with("Group 1 has 0 item types in common instead of exactly one badge"), "{}", error_chain(&error));
+}
+
+#[test]
+fn day4_rejects_backwards_ranges_and_malformed_numbers() {
+    let solver = Registry::get(2022, 4, Part::One).expect("day 4 is registered");
+
+    let error = solver.solve(&InputSource::Text(String::from("2-4,6-8\n5-3,1-2\n"))).expect_err("ranges must run forwards");
+    assert!(matches!(error, DayError::InternalDayError { day: 4, .. }), "{:?}", error);
+    assert!(error_chain(&error).ends_with("Pair 2 has the range 5-3, which ends before it starts"), "{}", error_chain(&error));
+
+    let error = solver.solve(&InputSource::Text(String::from("2-4,6-x\n"))).expect_err("sections must be numbers");
+    assert!(error_chain(&error).contains("line 1, column 7: expected a section number"), "{}", error_chain(&error));
+}
+
+#[test]
+fn day5_reads_the_drawing_by_column() {
+    let solve = |part: Part, input: &str| {
+        Registry::get(2022, 5, part).expect("day 5 is registered").solve(&InputSource::Text(input.to_string()))
+    };
+
+    let padded = "    [D]    \r\n[N] [C]    \r\n[Z] [M] [P]\r\n 1   2   3 \r\n\r\nmove 1 from 2 to 1\r\n";
+    assert_eq!(solve(Part::One, padded).unwrap(), Answer::from("DCP"));
+
+    let misaligned = "[N]  [C]\n[Z] [M] [P]\n 1   2   3\n\nmove 1 from 2 to 1\n";
+    let error = solve(Part::One, misaligned).expect_err("crates have to line up with their stack");
+    assert!(error_chain(&error).contains("line 1, column 5: expected a crate like [A] or three spaces"), "{}", error_chain(&error));
+
+    let floating = "[N]\n    [C]\n[Z] [M]\n 1   2\n\nmove 1 from 2 to 1\n";
+    let error = solve(Part::Two, floating).expect_err("crates have to sit on something");
+    assert!(error_chain(&error).ends_with("Crate [N] on line 1 is floating above an empty space in stack 1"), "{}", error_chain(&error));
+
+    let unnumbered = "[N] [C] [P]\n 1   2\n\nmove 1 from 2 to 1\n";
+    let error = solve(Part::Two, unnumbered).expect_err("every column needs a stack number");
+    assert!(error_chain(&error).ends_with("Crate [P] on line 1 is in column 3, but there are only 2 numbered stacks"), "{}", error_chain(&error));
+
+    let error = solve(Part::One, "[N] [C]\n 1   2\n\nmove 2 from 2 to 3\n").expect_err("stack 3 does not exist");
+    assert!(error_chain(&error).ends_with("Step 1 uses stack 3, but there are only stacks 1 to 2"), "{}", error_chain(&error));
+}
+
+#[test]
+fn day6_answers_one_stream_with_a_number_and_reports_streams_without_a_marker() {
+    let solve = |part: Part, input: &str| {
+        Registry::get(2022, 6, part).expect("day 6 is registered").solve(&InputSource::Text(input.to_string()))
+    };
+
+    assert_eq!(solve(Part::One, "mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap(), Answer::Integer(7));
+    assert_eq!(solve(Part::Two, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\nbvwbjplbgvbhsrlpgdmjqwftvncz\n").unwrap(), Answer::grid(["19", "23"]));
+
+    let error = solve(Part::One, "mjqjpqmgbljsphdztnvjfqwrcgsmlb\nabcabcabc\n").expect_err("the second stream repeats itself");
+    assert!(error_chain(&error).ends_with("Datastream 2 never has 4 different characters in a row"), "{}", error_chain(&error));
+}
+
+#[test]
+fn day7_rejects_inconsistent_transcripts() {
+    let solve = |input: &str| {
+        Registry::get(2022, 7, Part::One).expect("day 7 is registered").solve(&InputSource::Text(input.to_string()))
+    };
+
+    assert_eq!(solve("$ cd /\n$ ls\ndir a\n10 b\n$ cd a\n$ ls\n20 c\n$ cd ..\n$ cd a\n").unwrap(), Answer::Integer(50));
+
+    let error = solve("$ cd /\n$ ls\ndir a\n$ cd a\n$ ls\n$ cd b\n").expect_err("b was never listed");
+    assert!(matches!(error, DayError::InternalDayError { day: 7, .. }), "{:?}", error);
+    assert!(error_chain(&error).ends_with("Line 6 changes into directory b, which no earlier listing of /a showed"), "{}", error_chain(&error));
+
+    let error = solve("$ cd /\n$ cd ..\n").expect_err("the root has no parent");
+    assert!(error_chain(&error).ends_with("Line 2 changes to the parent of the root directory"), "{}", error_chain(&error));
+
+    let error = solve("$ cd /\n$ ls\ndir a\n$ cd a\n14 b\n").expect_err("output needs an ls");
+    assert!(error_chain(&error).ends_with("Line 5 is a listing entry, but it does not follow an 'ls'"), "{}", error_chain(&error));
+}
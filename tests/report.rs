@@ -0,0 +1,54 @@
+use std::{io, time::Duration};
+
+use aoc::{answers::Answers, days::{Answer, DayError, Part, registry::SolverKey}, report::{self, OutputFormat}, runner::{PhaseTimings, RunOutcome, RunStatus}};
+
+fn outcomes() -> Vec<RunOutcome> {
+    let timings = PhaseTimings {
+        read: Duration::from_nanos(10),
+        parse: Duration::from_nanos(20),
+        solve: Duration::from_nanos(30)
+    };
+    let failure = DayError::InternalDayError {
+        day: 1,
+        part: Part::Two,
+        source: Box::new(io::Error::other("bad elf"))
+    };
+
+    vec![
+        RunOutcome { key: SolverKey { year: 2022, day: 1, part: Part::One }, status: RunStatus::Solved(Answer::from(24000u32)), timings },
+        RunOutcome { key: SolverKey { year: 2022, day: 1, part: Part::Two }, status: RunStatus::Failed(failure), timings: PhaseTimings::default() }
+    ]
+}
+
+#[test]
+fn json_report_follows_the_schema() {
+    let mut answers = Answers::default();
+    answers.record(2022, 1, Part::One, Answer::from(24000u32));
+
+    let rendered = report::render_runs(OutputFormat::Json, &outcomes(), &answers).expect("json renders");
+    let json: serde_json::Value = serde_json::from_str(&rendered).expect("valid json");
+
+    assert_eq!(json["schema_version"], 2);
+    let solved = &json["results"][0];
+    assert_eq!(solved["part"], 1);
+    assert_eq!(solved["status"], "ok");
+    assert_eq!(solved["answer"], 24000);
+    assert_eq!(solved["check"], "pass");
+    assert_eq!(solved["timings"]["total_ns"], 60);
+    assert!(solved["error"].is_null());
+
+    let failed = &json["results"][1];
+    assert_eq!(failed["status"], "error");
+    assert_eq!(failed["error"]["kind"], "InternalDayError");
+    assert_eq!(failed["error"]["chain"], serde_json::json!(["Error returned from internal solver for Day 1a", "bad elf"]));
+}
+
+#[test]
+fn csv_report_has_one_row_per_run() {
+    let rendered = report::render_runs(OutputFormat::Csv, &outcomes(), &Answers::default()).expect("csv renders");
+    let lines = rendered.lines().collect::<Vec<&str>>();
+
+    assert_eq!(lines[0], "year,day,part,status,answer,check,expected,read_ns,parse_ns,solve_ns,total_ns,error_kind,error");
+    assert_eq!(lines[1], "2022,1,1,ok,24000,unknown,,10,20,30,60,,");
+    assert_eq!(lines[2], "2022,1,2,error,,,,0,0,0,0,InternalDayError,Error returned from internal solver for Day 1a: bad elf");
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use std::{path::Path, process::{Command, Output}};
+
+use aoc::{days::{day_input_path, DEFAULT_INPUT_DIR, InputSource, registry::{Registry, SolverKey}}, runner::{RunOutcome, Runner}};
+
+use common::scratch_dir;
+
+fn run_aoc(args: &[&str], input_dir: &Path) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc")).args(args).arg("--input-dir").arg(input_dir).env_remove("AOC_YEAR").output().expect("run aoc")
+}
+
+fn assert_send<T: Send>() {}
+
+#[test]
+fn outcomes_can_cross_threads() {
+    assert_send::<RunOutcome>();
+}
+
+#[test]
+fn parallel_runs_keep_registry_order() {
+    let solvers = Registry::all();
+    let source = InputSource::Directory(DEFAULT_INPUT_DIR.into());
+
+    let serial = Runner::run_all(&solvers, &source, 1);
+    let parallel = Runner::run_all(&solvers, &source, 4);
+
+    let keys = |outcomes: &[RunOutcome]| { outcomes.iter().map(|outcome| { outcome.key }).collect::<Vec<SolverKey>>() };
+    assert_eq!(keys(&parallel), keys(&serial));
+    assert_eq!(keys(&parallel), solvers.iter().map(|solver| { solver.key }).collect::<Vec<SolverKey>>());
+    for (parallel, serial) in parallel.iter().zip(serial.iter()) {
+        assert_eq!(parallel.status.label(), serial.status.label(), "{}", parallel.key);
+    }
+}
+
+#[test]
+fn all_skips_days_without_an_input_file_and_still_succeeds() {
+    let input_dir = Path::new(DEFAULT_INPUT_DIR);
+    let unfetched = Registry::all().into_iter()
+        .filter(|solver| { !day_input_path(input_dir, solver.key.year, solver.key.day).exists() })
+        .map(|solver| { solver.key })
+        .collect::<Vec<SolverKey>>();
+
+    let output = run_aoc(&["--all", "--format", "json"], input_dir);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).expect("JSON report");
+    let records = report["results"].as_array().expect("one record per run");
+    assert_eq!(records.len(), Registry::all().len());
+    for record in records {
+        let fetched = !unfetched.iter().any(|key| { record["year"] == key.year && record["day"] == key.day && record["part"] == key.part.number() });
+        assert_eq!(record["status"], if fetched { "ok" } else { "skipped" }, "{}", record);
+    }
+}
+
+#[test]
+fn missing_inputs_fail_days_that_were_asked_for() {
+    let input_dir = scratch_dir("runner", "empty");
+
+    for args in [&["--day", "1"][..], &["--day", "1", "--format", "json"], &["--day", "1", "--bench", "2"]] {
+        let output = run_aoc(args, &input_dir);
+        assert_eq!(output.status.code(), Some(1), "{:?}", args);
+    }
+
+    let output = run_aoc(&["--days", "1..=2", "--format", "json"], &input_dir);
+    assert_eq!(output.status.code(), Some(1), "a run that skips everything checks nothing");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("were skipped because their input files are missing"));
+}
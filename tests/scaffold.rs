@@ -0,0 +1,104 @@
+use std::fs;
+
+use aoc::scaffold::{add_example_test, add_module_declaration, add_year_declaration, render_module, render_year_module, scaffold_day, ScaffoldError};
+
+const DAYS_MOD: &str = "pub mod registry;
+pub mod y2021;
+pub mod y2022;
+
+use std::fmt;
+";
+
+const YEAR_MOD: &str = "//! Solutions to Advent of Code 2022.
+
+pub mod day1;
+pub mod day2;
+pub mod day10;
+";
+
+const EXAMPLE_TESTS: &str = "/// Generates one test per day, e.g. `example_tests! { y2022_day1 => (2022, 1) }` creates `y2022_day1`.
+macro_rules! example_tests { () => {} }
+
+example_tests! {
+    y2022_day1 => (2022, 1),
+    y2022_day2 => (2022, 2)
+}
+
+#[test]
+fn other() {}
+";
+
+#[test]
+fn module_declarations_stay_in_day_order() {
+    let year_mod = add_module_declaration(YEAR_MOD, 3).expect("day modules present");
+    assert!(year_mod.contains("pub mod day2;\npub mod day3;\npub mod day10;\n"), "{}", year_mod);
+
+    let year_mod = add_module_declaration(YEAR_MOD, 11).expect("day modules present");
+    assert!(year_mod.ends_with("pub mod day10;\npub mod day11;\n"), "{}", year_mod);
+
+    assert_eq!(add_module_declaration(YEAR_MOD, 2).as_deref(), Some(YEAR_MOD));
+    assert_eq!(add_module_declaration("pub mod registry;\n", 3), None);
+}
+
+#[test]
+fn year_declarations_stay_in_year_order() {
+    let days_mod = add_year_declaration(DAYS_MOD, 2015).expect("year modules present");
+    assert!(days_mod.contains("pub mod registry;\npub mod y2015;\npub mod y2021;\n"), "{}", days_mod);
+
+    let days_mod = add_year_declaration(DAYS_MOD, 2023).expect("year modules present");
+    assert!(days_mod.contains("pub mod y2022;\npub mod y2023;\n\nuse std::fmt;"), "{}", days_mod);
+
+    assert_eq!(add_year_declaration(DAYS_MOD, 2022).as_deref(), Some(DAYS_MOD));
+    assert_eq!(add_year_declaration("pub mod day1;\n", 2022), None);
+}
+
+#[test]
+fn example_tests_gain_an_entry_for_the_new_day() {
+    let example_tests = add_example_test(EXAMPLE_TESTS, 2022, 3).expect("macro call present");
+    assert!(
+        example_tests.contains("example_tests! {\n    y2022_day1 => (2022, 1),\n    y2022_day2 => (2022, 2),\n    y2022_day3 => (2022, 3)\n}\n\n#[test]"),
+        "{}", example_tests
+    );
+    assert!(example_tests.starts_with("/// Generates one test per day, e.g. `example_tests! { y2022_day1 => (2022, 1) }`"));
+
+    let example_tests = add_example_test(EXAMPLE_TESTS, 2021, 25).expect("macro call present");
+    assert!(example_tests.contains("example_tests! {\n    y2021_day25 => (2021, 25),\n    y2022_day1 => (2022, 1),"), "{}", example_tests);
+
+    assert_eq!(add_example_test("fn main() {}\n", 2022, 3), None);
+}
+
+#[test]
+fn template_is_filled_in_for_the_day() {
+    let module = render_module(2023, 7);
+
+    assert!(!module.contains("{{DAY}}") && !module.contains("{{YEAR}}"));
+    assert!(module.contains("pub(super) struct Day7;"));
+    assert!(module.contains("const YEAR: u16 = 2023;"));
+    assert!(module.contains("const DAY: u8 = 7;"));
+    assert!(module.contains("pub(super) enum Day7Error {"));
+    assert!(module.contains("register_solution!(Day7);"));
+}
+
+#[test]
+fn scaffolding_writes_every_file_once() {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("src").join("days").join("y2022")).unwrap();
+    fs::create_dir_all(root.join("tests")).unwrap();
+    fs::write(root.join("src").join("days").join("mod.rs"), DAYS_MOD).unwrap();
+    fs::write(root.join("src").join("days").join("y2022").join("mod.rs"), YEAR_MOD).unwrap();
+    fs::write(root.join("tests").join("examples.rs"), EXAMPLE_TESTS).unwrap();
+
+    let paths = scaffold_day(&root, 2022, 3).expect("scaffold succeeds");
+    assert_eq!(fs::read_to_string(&paths.module).unwrap(), render_module(2022, 3));
+    assert!(fs::read_to_string(&paths.year_mod).unwrap().contains("pub mod day3;"));
+    assert!(fs::read_to_string(&paths.example_tests).unwrap().contains("y2022_day3 => (2022, 3)"));
+
+    let paths = scaffold_day(&root, 2023, 1).expect("scaffold of a new year succeeds");
+    assert_eq!(fs::read_to_string(&paths.year_mod).unwrap(), render_year_module(2023, 1));
+    assert!(fs::read_to_string(&paths.days_mod).unwrap().contains("pub mod y2022;\npub mod y2023;\n"));
+
+    assert!(matches!(scaffold_day(&root, 2022, 3), Err(ScaffoldError::ModuleExistsError { .. })));
+    assert!(matches!(scaffold_day(&root, 2022, 26), Err(ScaffoldError::InvalidDayError { day: 26 })));
+    assert!(matches!(scaffold_day(&root, 2014, 1), Err(ScaffoldError::InvalidYearError { year: 2014 })));
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use std::time::Duration;
+
+use aoc::{days::{Answer, Part}, guesses::{GuessRefusal, GuessResult, Guesses}, submit::{parse_response, SubmitError, SubmitOutcome, Submitter}};
+
+use common::MockServer;
+
+const TOO_HIGH: &str = "<html><body><main><article><p>That's not the right answer; your answer is too high.  If you're stuck, \
+    make sure you're using the full input data. Please wait one minute before trying again. [<a href=\"/2022/day/1\">Return to Day 1</a>]</p></article></main></body></html>";
+
+#[test]
+fn responses_are_parsed() {
+    assert_eq!(parse_response("<article><p>That's the right answer!  You are <em>one gold star</em> closer.</p></article>").unwrap(), SubmitOutcome::Correct);
+    assert_eq!(parse_response(TOO_HIGH).unwrap(), SubmitOutcome::Wrong { result: GuessResult::TooHigh, wait: Some(Duration::from_secs(60)) });
+    assert_eq!(
+        parse_response("<article><p>That's not the right answer; your answer is too low. Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again.</p></article>").unwrap(),
+        SubmitOutcome::Wrong { result: GuessResult::TooLow, wait: Some(Duration::from_secs(300)) }
+    );
+    assert_eq!(
+        parse_response("<article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article>").unwrap(),
+        SubmitOutcome::Wrong { result: GuessResult::Wrong, wait: None }
+    );
+    assert_eq!(
+        parse_response("<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.</p></article>").unwrap(),
+        SubmitOutcome::TooSoon { wait: Duration::from_secs(83) }
+    );
+    assert_eq!(
+        parse_response("<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>").unwrap(),
+        SubmitOutcome::WrongLevel
+    );
+    assert!(parse_response("<article><p>Something else entirely</p></article>").is_err());
+}
+
+#[test]
+fn answers_ruled_out_by_earlier_guesses_are_refused() {
+    let mut guesses = Guesses::default();
+    guesses.record(2022, 1, Part::One, Answer::Integer(500), GuessResult::TooHigh);
+    guesses.record(2022, 1, Part::One, Answer::Integer(100), GuessResult::TooLow);
+    guesses.record(2022, 1, Part::One, Answer::Integer(300), GuessResult::Wrong);
+
+    assert_eq!(guesses.check(2022, 1, Part::One, &Answer::Integer(300), 0), Err(GuessRefusal::DuplicateGuess { answer: Answer::Integer(300), result: GuessResult::Wrong }));
+    assert_eq!(guesses.check(2022, 1, Part::One, &Answer::Integer(600), 0), Err(GuessRefusal::TooHigh { answer: 600, bound: 500 }));
+    assert_eq!(guesses.check(2022, 1, Part::One, &Answer::Integer(50), 0), Err(GuessRefusal::TooLow { answer: 50, bound: 100 }));
+    assert_eq!(guesses.check(2022, 1, Part::One, &Answer::Integer(200), 0), Ok(()));
+    assert_eq!(guesses.check(2022, 1, Part::Two, &Answer::Integer(600), 0), Ok(()));
+    assert_eq!(guesses.check(2022, 1, Part::One, &Answer::grid(["#.", ".#"]), 0), Err(GuessRefusal::GridAnswer {}));
+
+    guesses.wait_until(1_000);
+    assert_eq!(guesses.check(2022, 1, Part::One, &Answer::Integer(200), 940), Err(GuessRefusal::RateLimited { seconds: 60 }));
+    assert_eq!(guesses.check(2022, 1, Part::One, &Answer::Integer(200), 1_000), Ok(()));
+
+    guesses.record(2022, 1, Part::One, Answer::Integer(200), GuessResult::Correct);
+    assert!(matches!(guesses.check(2022, 1, Part::One, &Answer::Integer(201), 1_000), Err(GuessRefusal::AlreadySolved { .. })));
+
+    let reloaded = Guesses::from_toml(&guesses.to_toml().expect("guesses serialize")).expect("guesses parse");
+    assert_eq!(reloaded, guesses);
+}
+
+#[test]
+fn submits_the_answer_as_a_form_with_the_session_cookie() {
+    let server = MockServer::start("200 OK", TOO_HIGH);
+    let submitter = Submitter::new(&server.base_url, Some("secret-token"));
+
+    let outcome = submitter.submit(2022, 1, Part::Two, &Answer::Integer(72017)).expect("submission succeeds");
+    assert_eq!(outcome, SubmitOutcome::Wrong { result: GuessResult::TooHigh, wait: Some(Duration::from_secs(60)) });
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("POST /2022/day/1/answer HTTP/1.1"), "{}", requests[0]);
+    assert!(requests[0].contains("session=secret-token"), "{}", requests[0]);
+    assert!(requests[0].ends_with("level=2&answer=72017"), "{}", requests[0]);
+}
+
+#[test]
+fn nothing_is_posted_without_a_session() {
+    let server = MockServer::start("200 OK", TOO_HIGH);
+    let submitter = Submitter::new(&server.base_url, Some(" "));
+
+    let error = submitter.submit(2022, 1, Part::One, &Answer::Integer(1)).expect_err("there is no session");
+    assert!(matches!(error, SubmitError::MissingSessionError { .. }), "{:?}", error);
+    assert!(error.to_string().starts_with("No session token"), "{}", error);
+    assert!(server.requests().is_empty());
+}